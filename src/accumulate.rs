@@ -0,0 +1,446 @@
+//! Accumulators for checked aggregation of values.
+
+use {
+    crate::{
+        convert::SaturatingFrom,
+        convert_impls::array::SliceLimitedDebug,
+        error::error,
+        ops::{Cadd, Cmul},
+    },
+    core::fmt::{Debug, Display},
+};
+
+/// Running checked sum of values.
+///
+/// Each added value is checked with [`cadd`](crate::ops::cadd). If an addition fails,
+/// the error is returned and the sum retains its previous value.
+/// ```
+/// use cadd::accumulate::CheckedSum;
+///
+/// let mut sum = CheckedSum::<u8>::default();
+/// sum.add(100).unwrap();
+/// sum.try_extend([50, 50]).unwrap();
+/// assert_eq!(sum.add(100).unwrap_err().message(), "overflow: 200 + 100");
+/// assert_eq!(sum.finish(), 200);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CheckedSum<T> {
+    total: T,
+}
+
+impl<T: Cadd<Output = T> + Copy> CheckedSum<T> {
+    /// Creates a new sum with the specified initial value.
+    #[inline]
+    pub fn new(initial: T) -> Self {
+        Self { total: initial }
+    }
+
+    /// Adds a value to the sum.
+    #[inline]
+    #[track_caller]
+    pub fn add(&mut self, value: T) -> Result<(), T::Error> {
+        self.total = self.total.cadd(value)?;
+        Ok(())
+    }
+
+    /// Adds all values to the sum, stopping at the first error.
+    #[inline]
+    #[track_caller]
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, values: I) -> Result<(), T::Error> {
+        for value in values {
+            self.add(value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the sum.
+    #[inline]
+    pub fn finish(self) -> T {
+        self.total
+    }
+}
+
+/// Running checked product of values.
+///
+/// Each value is checked with [`cmul`](crate::ops::cmul). If a multiplication fails,
+/// the error is returned and the product retains its previous value.
+/// ```
+/// use cadd::accumulate::CheckedProduct;
+///
+/// let mut product = CheckedProduct::new(1u8);
+/// product.try_extend([2, 3, 4]).unwrap();
+/// assert_eq!(product.mul(11).unwrap_err().message(), "overflow: 24 * 11");
+/// assert_eq!(product.finish(), 24);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckedProduct<T> {
+    total: T,
+}
+
+impl<T: Cmul<Output = T> + Copy> CheckedProduct<T> {
+    /// Creates a new product with the specified initial value.
+    #[inline]
+    pub fn new(initial: T) -> Self {
+        Self { total: initial }
+    }
+
+    /// Multiplies the product by a value.
+    #[inline]
+    #[track_caller]
+    pub fn mul(&mut self, value: T) -> Result<(), T::Error> {
+        self.total = self.total.cmul(value)?;
+        Ok(())
+    }
+
+    /// Multiplies the product by all values, stopping at the first error.
+    #[inline]
+    #[track_caller]
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, values: I) -> Result<(), T::Error> {
+        for value in values {
+            self.mul(value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the product.
+    #[inline]
+    pub fn finish(self) -> T {
+        self.total
+    }
+}
+
+/// Running checked mean of integer samples.
+///
+/// It keeps only the sum and the count of the samples. If adding a sample overflows the sum,
+/// the error is returned and the accumulator retains its previous state.
+///
+/// The mean is computed as `sum as f64 / count as f64`, so it's exact only if the sum and the count
+/// are representable in `f64` (i.e. their absolute values don't exceed 2<sup>53</sup>).
+/// ```
+/// use cadd::accumulate::CheckedMean;
+///
+/// let mut mean = CheckedMean::default();
+/// mean.try_extend([1, 2, 3, 4]).unwrap();
+/// assert_eq!(mean.finish().unwrap(), 2.5);
+/// assert!(CheckedMean::default().finish().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CheckedMean {
+    sum: i64,
+    count: u64,
+}
+
+impl CheckedMean {
+    /// Adds a sample.
+    #[inline]
+    #[track_caller]
+    pub fn add(&mut self, sample: i64) -> crate::Result<()> {
+        let sum = self.sum.cadd(sample)?;
+        // Can't overflow in practice: it would take more than 2^64 calls.
+        self.count = self.count.cadd(1u64)?;
+        self.sum = sum;
+        Ok(())
+    }
+
+    /// Adds all samples, stopping at the first error.
+    #[inline]
+    #[track_caller]
+    pub fn try_extend<I: IntoIterator<Item = i64>>(&mut self, samples: I) -> crate::Result<()> {
+        for sample in samples {
+            self.add(sample)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the mean of the samples. Returns an error if no samples were added.
+    #[inline]
+    #[track_caller]
+    pub fn finish(self) -> crate::Result<f64> {
+        if self.count == 0 {
+            return Err(error!("mean", "cannot compute the mean of zero samples"));
+        }
+        Ok(self.sum as f64 / self.count as f64)
+    }
+}
+
+/// Running minimum and maximum of values.
+/// ```
+/// use cadd::accumulate::CheckedMinMax;
+///
+/// let mut min_max = CheckedMinMax::default();
+/// min_max.extend([3, -1, 7]);
+/// assert_eq!(min_max.finish().unwrap(), (-1, 7));
+/// assert!(CheckedMinMax::<i32>::default().finish().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckedMinMax<T> {
+    range: Option<(T, T)>,
+}
+
+impl<T> Default for CheckedMinMax<T> {
+    #[inline]
+    fn default() -> Self {
+        Self { range: None }
+    }
+}
+
+impl<T: Ord + Copy> CheckedMinMax<T> {
+    /// Adds a value.
+    #[inline]
+    pub fn add(&mut self, value: T) {
+        self.range = Some(match self.range {
+            Some((min, max)) => (min.min(value), max.max(value)),
+            None => (value, value),
+        });
+    }
+
+    /// Adds all values.
+    #[inline]
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.add(value);
+        }
+    }
+
+    /// Returns `(min, max)`. Returns an error if no values were added.
+    #[inline]
+    #[track_caller]
+    pub fn finish(self) -> crate::Result<(T, T)> {
+        match self.range {
+            Some(range) => Ok(range),
+            None => Err(error!("min_max", "cannot compute the range of zero values")),
+        }
+    }
+}
+
+/// Sum of all values. Returns zero for an empty slice.
+///
+/// On overflow, the error message contains all values, the running sum and the index of the value
+/// that caused the overflow.
+/// ```
+/// use cadd::accumulate::cadd_all;
+///
+/// assert_eq!(cadd_all(&[1u8, 2, 3]).unwrap(), 6);
+/// assert_eq!(
+///     cadd_all(&[100u8, 100, 100]).unwrap_err().message(),
+///     "overflow adding [100, 100, 100]: running sum 200 + 100 at index 2",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn cadd_all<T>(values: &[T]) -> crate::Result<T>
+where
+    T: Cadd<Output = T> + Default + Copy + Debug + Display,
+{
+    let mut total = T::default();
+    for (index, &value) in values.iter().enumerate() {
+        total = match total.cadd(value) {
+            Ok(total) => total,
+            Err(_) => {
+                return Err(error!(
+                    "add",
+                    "overflow adding {:?}: running sum {} + {} at index {}",
+                    SliceLimitedDebug(values),
+                    total,
+                    value,
+                    index,
+                ))
+            }
+        };
+    }
+    Ok(total)
+}
+
+/// Sum of all values. Returns zero for an empty slice.
+///
+/// Unlike [`cadd_all`], the error message doesn't contain all values, only the index of the value
+/// that caused the overflow, the value itself and the running sum, so it stays short for large slices.
+/// ```
+/// use cadd::accumulate::cadd_indexed;
+///
+/// assert_eq!(cadd_indexed(&[1u8, 2, 3]).unwrap(), 6);
+/// assert_eq!(
+///     cadd_indexed(&[100u8, 100, 100]).unwrap_err().message(),
+///     "overflow at index 2: 200 + 100",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn cadd_indexed<T>(values: &[T]) -> crate::Result<T>
+where
+    T: Cadd<Output = T> + Default + Copy + Display,
+{
+    let mut total = T::default();
+    for (index, &value) in values.iter().enumerate() {
+        total = match total.cadd(value) {
+            Ok(total) => total,
+            Err(_) => {
+                return Err(error!(
+                    "add",
+                    "overflow at index {}: {} + {}", index, total, value,
+                ))
+            }
+        };
+    }
+    Ok(total)
+}
+
+/// Product of all values. Returns one for an empty slice.
+///
+/// On overflow, the error message contains all values, the running product and the index of the value
+/// that caused the overflow.
+/// ```
+/// use cadd::accumulate::cmul_all;
+///
+/// assert_eq!(cmul_all(&[2u8, 3, 4]).unwrap(), 24);
+/// assert_eq!(
+///     cmul_all(&[2u8, 3, 4, 11]).unwrap_err().message(),
+///     "overflow multiplying [2, 3, 4, 11]: running product 24 * 11 at index 3",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn cmul_all<T>(values: &[T]) -> crate::Result<T>
+where
+    T: Cmul<Output = T> + SaturatingFrom<u8> + Copy + Debug + Display,
+{
+    let mut total = T::saturating_from(1);
+    for (index, &value) in values.iter().enumerate() {
+        total = match total.cmul(value) {
+            Ok(total) => total,
+            Err(_) => {
+                return Err(error!(
+                    "mul",
+                    "overflow multiplying {:?}: running product {} * {} at index {}",
+                    SliceLimitedDebug(values),
+                    total,
+                    value,
+                    index,
+                ))
+            }
+        };
+    }
+    Ok(total)
+}
+
+/// Sum of values of type `T` accumulated in a wider type `W`, e.g. `u32` values into `u64`.
+///
+/// If even the wider type overflows, the sum is clamped to the bounds of `W`,
+/// same as with `saturating_add`. Use [`cwsum`] to get an error instead.
+/// ```
+/// use cadd::accumulate::wsum;
+///
+/// assert_eq!(wsum::<u32, u64>([u32::MAX, u32::MAX]), 2 * u64::from(u32::MAX));
+/// assert_eq!(wsum::<i8, i16>([-128, -128]), -256);
+/// ```
+#[inline]
+pub fn wsum<T, W>(values: impl IntoIterator<Item = T>) -> W
+where
+    W: From<T>
+        + Cadd<Output = W>
+        + SaturatingFrom<u8>
+        + SaturatingFrom<i128>
+        + SaturatingFrom<u128>,
+    W: PartialOrd + Copy,
+{
+    let zero = W::saturating_from(0u8);
+    let mut total = zero;
+    for value in values {
+        let value = W::from(value);
+        total = match total.cadd(value) {
+            Ok(total) => total,
+            Err(_) if value > zero => W::saturating_from(u128::MAX),
+            Err(_) => W::saturating_from(i128::MIN),
+        };
+    }
+    total
+}
+
+/// Sum of values of type `T` accumulated in a wider type `W`, e.g. `u32` values into `u64`.
+///
+/// Returns an error if even the wider type overflows. The error message contains the running sum
+/// and the index of the value that caused the overflow.
+/// ```
+/// use cadd::accumulate::cwsum;
+///
+/// assert_eq!(cwsum::<u8, u16>([200, 200]).unwrap(), 400);
+/// assert_eq!(
+///     cwsum::<u16, u16>([60000, 60000]).unwrap_err().message(),
+///     "overflow adding values: running sum 60000 + 60000 at index 1",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn cwsum<T, W>(values: impl IntoIterator<Item = T>) -> crate::Result<W>
+where
+    W: From<T> + Cadd<Output = W> + SaturatingFrom<u8> + Copy + Display,
+{
+    let mut total = W::saturating_from(0u8);
+    for (index, value) in values.into_iter().enumerate() {
+        let value = W::from(value);
+        total = match total.cadd(value) {
+            Ok(total) => total,
+            Err(_) => {
+                return Err(error!(
+                    "add",
+                    "overflow adding values: running sum {} + {} at index {}", total, value, index,
+                ))
+            }
+        };
+    }
+    Ok(total)
+}
+
+/// Dot product of two slices: `a[0] * b[0] + a[1] * b[1] + ...`. Returns zero for empty slices.
+///
+/// Returns an error if the slices have different lengths or on overflow. The error message contains
+/// the index at which the overflow occurred.
+/// ```
+/// use cadd::accumulate::cdot;
+///
+/// assert_eq!(cdot(&[1i32, 2, 3], &[4, -5, 6]).unwrap(), 12);
+/// assert_eq!(
+///     cdot(&[1u8, 2], &[3]).unwrap_err().message(),
+///     "cannot compute dot product: lengths are different (2 and 1)",
+/// );
+/// assert_eq!(
+///     cdot(&[10u8, 20], &[10, 10]).unwrap_err().message(),
+///     "overflow in dot product at index 1: running sum 100 + 20 * 10",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn cdot<T>(a: &[T], b: &[T]) -> crate::Result<T>
+where
+    T: Cadd<Output = T> + Cmul<Output = T> + Default + Copy + Display,
+{
+    if a.len() != b.len() {
+        return Err(error!(
+            "dot",
+            "cannot compute dot product: lengths are different ({} and {})",
+            a.len(),
+            b.len(),
+        ));
+    }
+    let mut total = T::default();
+    for (index, (&x, &y)) in a.iter().zip(b).enumerate() {
+        let sum = match x.cmul(y) {
+            Ok(product) => total.cadd(product).ok(),
+            Err(_) => None,
+        };
+        total = match sum {
+            Some(sum) => sum,
+            None => {
+                return Err(error!(
+                    "dot",
+                    "overflow in dot product at index {}: running sum {} + {} * {}",
+                    index,
+                    total,
+                    x,
+                    y,
+                ))
+            }
+        };
+    }
+    Ok(total)
+}
@@ -0,0 +1,417 @@
+//! Encoding and decoding integers as bytes.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::error::error;
+
+/// Byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+/// Signed integers that can be encoded in sign-magnitude representation, in which the most significant bit
+/// is the sign and the remaining bits are the absolute value.
+///
+/// See [`from_sign_magnitude_bytes`] and [`to_sign_magnitude_bytes`].
+pub trait SignMagnitude: Sized {
+    /// Byte array of the same size as `Self`.
+    type Bytes;
+
+    /// Decodes the value. If `allow_negative_zero` is `false`, returns an error on the negative zero encoding.
+    /// Otherwise, it's decoded as zero.
+    fn from_sign_magnitude_bytes(
+        bytes: &[u8],
+        endian: Endian,
+        allow_negative_zero: bool,
+    ) -> crate::Result<Self>;
+
+    /// Encodes the value. Returns an error if the value is `Self::MIN` because its absolute value can't be represented.
+    fn to_sign_magnitude_bytes(self, endian: Endian) -> crate::Result<Self::Bytes>;
+}
+
+/// Decodes a signed integer from sign-magnitude representation.
+///
+/// Returns an error if the number of bytes doesn't match the size of the type.
+/// The negative zero encoding is decoded as zero. Use [`from_sign_magnitude_bytes_strict`] to reject it.
+/// ```
+/// use cadd::bytes::{from_sign_magnitude_bytes, Endian};
+///
+/// assert_eq!(from_sign_magnitude_bytes::<i16>(&[0x80, 0x05], Endian::Big).unwrap(), -5);
+/// assert_eq!(from_sign_magnitude_bytes::<i16>(&[0x05, 0x80], Endian::Little).unwrap(), -5);
+/// assert_eq!(from_sign_magnitude_bytes::<i16>(&[0x80, 0x00], Endian::Big).unwrap(), 0);
+/// assert!(from_sign_magnitude_bytes::<i16>(&[0x80], Endian::Big).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn from_sign_magnitude_bytes<T: SignMagnitude>(
+    bytes: &[u8],
+    endian: Endian,
+) -> crate::Result<T> {
+    T::from_sign_magnitude_bytes(bytes, endian, true)
+}
+
+/// Decodes a signed integer from sign-magnitude representation.
+///
+/// Returns an error if the number of bytes doesn't match the size of the type,
+/// or if the bytes contain the negative zero encoding.
+/// ```
+/// use cadd::bytes::{from_sign_magnitude_bytes_strict, Endian};
+///
+/// assert_eq!(from_sign_magnitude_bytes_strict::<i16>(&[0x80, 0x05], Endian::Big).unwrap(), -5);
+/// assert!(from_sign_magnitude_bytes_strict::<i16>(&[0x80, 0x00], Endian::Big).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn from_sign_magnitude_bytes_strict<T: SignMagnitude>(
+    bytes: &[u8],
+    endian: Endian,
+) -> crate::Result<T> {
+    T::from_sign_magnitude_bytes(bytes, endian, false)
+}
+
+/// Encodes a signed integer in sign-magnitude representation.
+///
+/// Returns an error if the value is `T::MIN` because its absolute value can't be represented.
+/// ```
+/// use cadd::bytes::{to_sign_magnitude_bytes, Endian};
+///
+/// assert_eq!(to_sign_magnitude_bytes(-5i16, Endian::Big).unwrap(), [0x80, 0x05]);
+/// assert!(to_sign_magnitude_bytes(i16::MIN, Endian::Big).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn to_sign_magnitude_bytes<T: SignMagnitude>(
+    value: T,
+    endian: Endian,
+) -> crate::Result<T::Bytes> {
+    value.to_sign_magnitude_bytes(endian)
+}
+
+macro_rules! impl_sign_magnitude {
+    ($(($signed:ty, $unsigned:ty),)*) => {
+        $(
+            impl SignMagnitude for $signed {
+                type Bytes = [u8; size_of::<$signed>()];
+
+                #[track_caller]
+                fn from_sign_magnitude_bytes(
+                    bytes: &[u8],
+                    endian: Endian,
+                    allow_negative_zero: bool,
+                ) -> crate::Result<Self> {
+                    let Ok(array) = <Self::Bytes>::try_from(bytes) else {
+                        return Err(error!(
+                            "convert",
+                            "cannot decode {} from sign-magnitude bytes: expected {} bytes, got {}",
+                            ::core::any::type_name::<$signed>(),
+                            size_of::<$signed>(),
+                            bytes.len(),
+                        ));
+                    };
+                    let raw = match endian {
+                        Endian::Big => <$unsigned>::from_be_bytes(array),
+                        Endian::Little => <$unsigned>::from_le_bytes(array),
+                    };
+                    let sign_bit = 1 << (<$unsigned>::BITS - 1);
+                    // Fits because the sign bit is cleared.
+                    let magnitude = (raw & !sign_bit) as $signed;
+                    if raw & sign_bit == 0 {
+                        Ok(magnitude)
+                    } else if magnitude == 0 && !allow_negative_zero {
+                        Err(error!(
+                            "convert",
+                            "cannot decode {} from sign-magnitude bytes: negative zero is not allowed: {:?}",
+                            ::core::any::type_name::<$signed>(),
+                            bytes,
+                        ))
+                    } else {
+                        Ok(-magnitude)
+                    }
+                }
+
+                #[track_caller]
+                fn to_sign_magnitude_bytes(self, endian: Endian) -> crate::Result<Self::Bytes> {
+                    if self == <$signed>::MIN {
+                        return Err(error!(
+                            "convert",
+                            "cannot encode {} as sign-magnitude: value is out of bounds",
+                            self,
+                        ));
+                    }
+                    let mut raw = self.unsigned_abs();
+                    if self < 0 {
+                        raw |= 1 << (<$unsigned>::BITS - 1);
+                    }
+                    Ok(match endian {
+                        Endian::Big => raw.to_be_bytes(),
+                        Endian::Little => raw.to_le_bytes(),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_sign_magnitude!(
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+    (i128, u128),
+    (isize, usize),
+);
+
+/// Infallible decoding of integers from byte arrays of the exact size.
+///
+/// Because the length is known statically, these methods don't return `Result`.
+/// Use the slice-based decoders (e.g. [`u128_from_slice_be`]) or [`Cfrom`](crate::convert::Cfrom)
+/// from `&[u8]` to a byte array if the length is only known at runtime.
+/// ```
+/// use cadd::bytes::FromByteArray;
+///
+/// assert_eq!(u32::from_be_array([0x12, 0x34, 0x56, 0x78]), 0x12345678);
+/// assert_eq!(u32::from_le_array([0x78, 0x56, 0x34, 0x12]), 0x12345678);
+/// assert_eq!(i16::from_be_array([0xFF, 0xFE]), -2);
+/// ```
+pub trait FromByteArray: Sized {
+    /// Byte array of the same size as `Self`.
+    type Array;
+
+    /// Decodes the value in big-endian order (most significant byte first).
+    fn from_be_array(bytes: Self::Array) -> Self;
+
+    /// Decodes the value in little-endian order (least significant byte first).
+    fn from_le_array(bytes: Self::Array) -> Self;
+
+    /// Decodes the value in the native byte order of the target platform.
+    fn from_ne_array(bytes: Self::Array) -> Self;
+}
+
+macro_rules! impl_from_byte_array {
+    ($($t:ty,)*) => {
+        $(
+            impl FromByteArray for $t {
+                type Array = [u8; size_of::<$t>()];
+
+                #[inline]
+                fn from_be_array(bytes: Self::Array) -> Self {
+                    <$t>::from_be_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_le_array(bytes: Self::Array) -> Self {
+                    <$t>::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_ne_array(bytes: Self::Array) -> Self {
+                    <$t>::from_ne_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_byte_array!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+/// Decodes a sequence of integers from bytes with the specified byte order.
+///
+/// Returns an error if the length of `bytes` is not a multiple of the size of `T`
+/// or if the vector can't be allocated.
+/// ```
+/// use cadd::bytes::{decode_vec, Endian};
+///
+/// assert_eq!(decode_vec::<u16>(&[1, 0, 2, 0], Endian::Little).unwrap(), [1, 2]);
+/// assert_eq!(
+///     decode_vec::<u16>(&[1, 0, 2], Endian::Little).unwrap_err().message(),
+///     "cannot decode u16 values from 3 bytes: length is not a multiple of 2 (remainder 1)",
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn decode_vec<T>(bytes: &[u8], endian: Endian) -> crate::Result<Vec<T>>
+where
+    T: FromByteArray,
+    T::Array: Default + AsMut<[u8]>,
+{
+    let size = size_of::<T>();
+    let remainder = bytes.len() % size;
+    if remainder != 0 {
+        return Err(error!(
+            "convert",
+            "cannot decode {} values from {} bytes: length is not a multiple of {} (remainder {})",
+            core::any::type_name::<T>(),
+            bytes.len(),
+            size,
+            remainder,
+        ));
+    }
+    let mut values = Vec::new();
+    crate::vec::ctry_reserve(&mut values, bytes.len() / size)?;
+    for chunk in bytes.chunks_exact(size) {
+        let mut array = T::Array::default();
+        array.as_mut().copy_from_slice(chunk);
+        values.push(match endian {
+            Endian::Big => T::from_be_array(array),
+            Endian::Little => T::from_le_array(array),
+        });
+    }
+    Ok(values)
+}
+
+/// Assembles a `u128` from 16 bytes in big-endian order (most significant byte first).
+///
+/// This is the usual layout of 128-bit digests and identifiers.
+/// ```
+/// use cadd::bytes::u128_from_bytes_be;
+///
+/// let mut digest = [0; 16];
+/// digest[15] = 1;
+/// assert_eq!(u128_from_bytes_be(&digest), 1);
+/// ```
+#[inline]
+pub fn u128_from_bytes_be(bytes: &[u8; 16]) -> u128 {
+    u128::from_be_bytes(*bytes)
+}
+
+/// Assembles a `u128` from 16 bytes in little-endian order (least significant byte first).
+/// ```
+/// use cadd::bytes::u128_from_bytes_le;
+///
+/// let mut digest = [0; 16];
+/// digest[0] = 1;
+/// assert_eq!(u128_from_bytes_le(&digest), 1);
+/// ```
+#[inline]
+pub fn u128_from_bytes_le(bytes: &[u8; 16]) -> u128 {
+    u128::from_le_bytes(*bytes)
+}
+
+/// Assembles a `u128` from a slice in big-endian order (most significant byte first).
+///
+/// Returns an error if the slice is not exactly 16 bytes long.
+/// ```
+/// use cadd::bytes::u128_from_slice_be;
+///
+/// assert_eq!(u128_from_slice_be(&[0xAB; 16]).unwrap(), u128::from_be_bytes([0xAB; 16]));
+/// assert!(u128_from_slice_be(&[0; 15]).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn u128_from_slice_be(bytes: &[u8]) -> crate::Result<u128> {
+    Ok(u128_from_bytes_be(&digest_array(bytes)?))
+}
+
+/// Assembles a `u128` from a slice in little-endian order (least significant byte first).
+///
+/// Returns an error if the slice is not exactly 16 bytes long.
+/// ```
+/// use cadd::bytes::u128_from_slice_le;
+///
+/// assert_eq!(u128_from_slice_le(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap(), 1);
+/// assert!(u128_from_slice_le(&[0; 17]).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn u128_from_slice_le(bytes: &[u8]) -> crate::Result<u128> {
+    Ok(u128_from_bytes_le(&digest_array(bytes)?))
+}
+
+/// Splits a 256-bit big-endian value (e.g. a SHA-256 digest) into two `u128` words.
+///
+/// The most significant word comes first, so comparing the returned arrays
+/// gives the same ordering as comparing the original bytes.
+/// ```
+/// use cadd::bytes::u256_words_from_bytes_be;
+///
+/// let mut digest = [0; 32];
+/// digest[0] = 0x80;
+/// digest[31] = 1;
+/// assert_eq!(u256_words_from_bytes_be(&digest), [1 << 127, 1]);
+/// ```
+#[inline]
+pub fn u256_words_from_bytes_be(bytes: &[u8; 32]) -> [u128; 2] {
+    let mut high = [0; 16];
+    let mut low = [0; 16];
+    high.copy_from_slice(&bytes[..16]);
+    low.copy_from_slice(&bytes[16..]);
+    [u128::from_be_bytes(high), u128::from_be_bytes(low)]
+}
+
+/// Splits a 256-bit big-endian value stored in a slice into two `u128` words,
+/// the most significant word first.
+///
+/// Returns an error if the slice is not exactly 32 bytes long.
+/// ```
+/// use cadd::bytes::u256_words_from_slice_be;
+///
+/// assert_eq!(u256_words_from_slice_be(&[0; 32]).unwrap(), [0, 0]);
+/// assert!(u256_words_from_slice_be(&[0; 16]).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn u256_words_from_slice_be(bytes: &[u8]) -> crate::Result<[u128; 2]> {
+    Ok(u256_words_from_bytes_be(&digest_array(bytes)?))
+}
+
+/// Reads a string prefixed with its length in bytes as a big-endian `u32`.
+///
+/// Returns the string and the bytes that follow it. Returns an error with the `"truncated"`
+/// [category](crate::Error::category) if `bytes` end before the length prefix or the string,
+/// and an error with the `"convert"` category if the string is not valid UTF-8.
+/// ```
+/// use cadd::bytes::read_len_prefixed_str;
+///
+/// let (text, rest) = read_len_prefixed_str(b"\0\0\0\x02hi!").unwrap();
+/// assert_eq!((text, rest), ("hi", &b"!"[..]));
+/// assert_eq!(read_len_prefixed_str(b"\0\0\0\x05hi").unwrap_err().category(), "truncated");
+/// ```
+#[inline]
+#[track_caller]
+pub fn read_len_prefixed_str(bytes: &[u8]) -> crate::Result<(&str, &[u8])> {
+    let Some((len, rest)) = bytes.split_first_chunk::<4>() else {
+        return Err(error!(
+            "truncated",
+            "cannot read length-prefixed string: expected 4 length bytes, got {}",
+            bytes.len(),
+        ));
+    };
+    let len = u32::from_be_bytes(*len);
+    let Some((payload, rest)) = usize::try_from(len)
+        .ok()
+        .and_then(|len| rest.split_at_checked(len))
+    else {
+        return Err(error!(
+            "truncated",
+            "cannot read length-prefixed string: expected {} payload bytes, got {}",
+            len,
+            rest.len(),
+        ));
+    };
+    match core::str::from_utf8(payload) {
+        Ok(text) => Ok((text, rest)),
+        Err(err) => Err(error!(
+            "convert",
+            "cannot read length-prefixed string: not a utf-8 string: {}", err,
+        )),
+    }
+}
+
+#[track_caller]
+fn digest_array<const N: usize>(bytes: &[u8]) -> crate::Result<[u8; N]> {
+    match bytes.try_into() {
+        Ok(array) => Ok(array),
+        Err(_) => Err(error!(
+            "convert",
+            "cannot decode a {}-bit value from bytes: expected {} bytes, got {}",
+            N * 8,
+            N,
+            bytes.len(),
+        )),
+    }
+}
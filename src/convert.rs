@@ -1,9 +1,17 @@
 //! Converting values to another type.
 
+#[cfg(feature = "alloc")]
+pub use crate::convert_impls::array::array_from_vec_padded;
+#[cfg(feature = "alloc")]
+pub use crate::convert_impls::map::map_values_cinto;
+#[cfg(feature = "num-rational")]
+pub use crate::convert_impls::ratio::ratio_from_f64;
+
 /// Extention trait that enables `.into_type::<T>()` syntax. Also works for
 /// [`cinto`](Cinto),
 /// [`try_into`](TryInto),
-/// [`saturating_into`](SaturatingInto).
+/// [`saturating_into`](SaturatingInto),
+/// [`reinterpret_into`](ReinterpretInto).
 ///
 /// When you replace unchecked type casts (e.g. `number as u32`) with an infallible conversion
 /// (`number.into()`) or a fallible conversion (`number.try_into()?`), you may often encounter
@@ -74,6 +82,7 @@ pub trait IntoType {
     /// assert_eq!(2i32.cinto_type::<u32>().unwrap(), 2);
     /// ```
     #[inline]
+    #[track_caller]
     fn cinto_type<T>(self) -> Result<T, Self::Error>
     where
         Self: Cinto<T>,
@@ -81,6 +90,30 @@ pub trait IntoType {
         self.cinto()
     }
 
+    /// Converts the value with [`.cinto()`](Cinto) and **panics** if the conversion fails.
+    ///
+    /// The panic message contains `msg` followed by the error, like [`Result::expect`].
+    /// This is intended for tests and prototypes. Use [`cinto_type`](Self::cinto_type)
+    /// to handle the error instead.
+    /// ```should_panic
+    /// use cadd::convert::IntoType;
+    /// assert_eq!(2i32.expect_into_type::<u32>("valid count"), 2);
+    /// // Panics with "valid count: cannot convert value -2 from i32 to u32: ..."
+    /// (-2i32).expect_into_type::<u32>("valid count");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn expect_into_type<T>(self, msg: &str) -> T
+    where
+        Self: Cinto<T>,
+        <Self as Cinto<T>>::Error: core::fmt::Debug,
+    {
+        match self.cinto() {
+            Ok(value) => value,
+            Err(err) => panic!("{msg}: {err:?}"),
+        }
+    }
+
     /// An alternative to [`.saturating_into()`](SaturatingInto) that allows specifying the target type.
     /// ```
     /// use cadd::convert::IntoType;
@@ -93,16 +126,102 @@ pub trait IntoType {
     {
         self.saturating_into()
     }
+
+    /// An alternative to [`.reinterpret_into()`](ReinterpretInto) that allows specifying the target type.
+    /// ```
+    /// use cadd::convert::IntoType;
+    /// assert_eq!((-2_i8).reinterpret_into_type::<u8>(), 254);
+    /// ```
+    #[inline]
+    fn reinterpret_into_type<T>(self) -> T
+    where
+        Self: ReinterpretInto<T>,
+    {
+        self.reinterpret_into()
+    }
 }
 
 impl<T: ?Sized> IntoType for T {}
 
+/// Checked conversion with [`Cinto`] that always returns [`crate::Error`].
+///
+/// The target type is inferred from the context, so it's enough to write `.ctry()?` when the result
+/// is bound to a variable with a type annotation, passed as a function argument, or returned.
+/// Since the error type is fixed, `?` works in functions returning any error type
+/// that implements `From<cadd::Error>`.
+/// ```
+/// use cadd::convert::CTry;
+///
+/// fn f(big: u64) -> cadd::Result<u8> {
+///     let value: u8 = big.ctry()?;
+///     Ok(value)
+/// }
+/// assert_eq!(f(200).unwrap(), 200);
+/// assert!(f(300).is_err());
+/// ```
+/// Inference doesn't work if the result is used in an expression,
+/// e.g. in `1 + big.ctry()?`. Use [`.cinto_type::<T>()`](IntoType::cinto_type) in this case:
+/// ```
+/// use cadd::convert::IntoType;
+///
+/// fn f(big: u64) -> cadd::Result<u16> {
+///     Ok(1 + big.cinto_type::<u16>()?)
+/// }
+/// assert_eq!(f(200).unwrap(), 201);
+/// ```
+pub trait CTry: Sized {
+    /// Converts the value to the type inferred from the context.
+    #[inline]
+    #[track_caller]
+    fn ctry<T>(self) -> crate::Result<T>
+    where
+        Self: Cinto<T, Error = crate::Error>,
+    {
+        self.cinto()
+    }
+}
+
+impl<T> CTry for T {}
+
 /// Checked conversion from `F` to `Self`.
 ///
 /// This is semantically the same as [`TryFrom`]. However, `Cfrom`
 /// aims to provide a rich error message, as opposed to many implementations of `TryFrom` in `std`
 /// that provide minimal informations in errors.
 ///
+/// `Cfrom` is implemented for every pair of integer primitives, including lossless conversions
+/// (e.g. `u8` to `u32` or `i64` to `i64`). This makes it usable with platform-dependent type aliases
+/// like [`c_long`](core::ffi::c_long) without any `cfg` attributes.
+///
+/// [`Wrapping<T>`](core::num::Wrapping) and [`Saturating<T>`](core::num::Saturating) can be converted
+/// to each other. These conversions always succeed: the value is preserved and only the arithmetic policy changes.
+/// ```
+/// use {cadd::convert::IntoType, std::num::{Saturating, Wrapping}};
+///
+/// let value = Wrapping(250u8).cinto_type::<Saturating<u8>>().unwrap();
+/// assert_eq!(value + Saturating(10), Saturating(255));
+/// ```
+///
+/// [`Reverse<T>`](core::cmp::Reverse) is converted by converting the wrapped value, so conversions
+/// work through it without unwrapping:
+/// ```
+/// use {cadd::convert::IntoType, std::cmp::Reverse};
+///
+/// assert_eq!(Reverse(5u64).cinto_type::<Reverse<u32>>().unwrap(), Reverse(5));
+/// assert!(Reverse(u64::MAX).cinto_type::<Reverse<u32>>().is_err());
+/// ```
+///
+/// Unsigned [`NonZero`](core::num::NonZero) integers can be parsed from `&str`:
+/// ```
+/// use {cadd::convert::Cfrom, std::num::NonZero};
+///
+/// assert_eq!(NonZero::<u32>::cfrom("5").unwrap().get(), 5);
+/// assert_eq!(
+///     NonZero::<u32>::cfrom("0").unwrap_err().message(),
+///     "cannot parse \"0\" as NonZero<u32>: zero is not allowed",
+/// );
+/// ```
+///
 /// [`Cinto`] trait provides an alternative way to do the same conversion.
 /// Similar to `TryFrom`, it's recommended to always implement `Cfrom` instead of [`Cinto`].
 /// The corresponding `Cinto` implementation will be covered by the blanket impl.
@@ -132,6 +251,7 @@ where
 {
     type Error = <I as Cfrom<F>>::Error;
     #[inline]
+    #[track_caller]
     fn cinto(self) -> Result<I, Self::Error> {
         I::cfrom(self)
     }
@@ -150,6 +270,25 @@ where
 /// assert_eq!(u8::saturating_from(-300_i32), 0);
 /// assert_eq!(i8::saturating_from(-300_i32), -128);
 /// ```
+/// It's also implemented for [`NonZero`](core::num::NonZero) targets. In this case, the value is clamped
+/// to the range of the underlying integer type and then zero is replaced with one:
+/// ```
+/// use {cadd::convert::SaturatingFrom, std::num::NonZero};
+///
+/// assert_eq!(NonZero::<u8>::saturating_from(300_u32).get(), 255);
+/// assert_eq!(NonZero::<u8>::saturating_from(0_u32).get(), 1);
+/// assert_eq!(NonZero::<u8>::saturating_from(-5_i32).get(), 1);
+/// assert_eq!(NonZero::<i8>::saturating_from(-300_i32).get(), -128);
+/// ```
+/// Conversions from `u128` and `i128` to `f32` and `f64` return `MAX` or `MIN` of the float type
+/// instead of infinity, which `as` casts produce for values too large for `f32`:
+/// ```
+/// use cadd::convert::SaturatingFrom;
+///
+/// assert_eq!(u128::MAX as f32, f32::INFINITY);
+/// assert_eq!(f32::saturating_from(u128::MAX), f32::MAX);
+/// assert_eq!(f32::saturating_from(i128::MIN), -1.7014118e38);
+/// ```
 /// [`SaturatingInto`] trait provides an alternative way to do the same conversion.
 /// Similar to [`TryFrom`], it's recommended to always implement
 /// `SaturatingFrom` instead of [`SaturatingInto`](Cinto).
@@ -196,6 +335,84 @@ where
     }
 }
 
+/// Reinterprets the bits of a same-width integer of the opposite signedness as `Self`.
+///
+/// Unlike [`Cfrom`], this conversion is **not** value-preserving: negative values become large unsigned values
+/// and vice versa, as with an `as` cast between types of the same width. Use it when the intent is to keep
+/// the same bits (e.g. when working with hashes or wire formats), and [`Cfrom`] when the intent is to keep the same value.
+/// ```
+/// use cadd::convert::ReinterpretFrom;
+///
+/// assert_eq!(u32::reinterpret_from(-1_i32), u32::MAX);
+/// assert_eq!(i8::reinterpret_from(200_u8), -56);
+/// assert_eq!(i64::reinterpret_from(5_u64), 5);
+/// ```
+/// It's implemented for all pairs of signed and unsigned integers with the same width, in both directions.
+///
+/// [`ReinterpretInto`] trait provides an alternative way to do the same conversion.
+/// The corresponding `ReinterpretInto` implementation will be covered by the blanket impl.
+pub trait ReinterpretFrom<F>: Sized {
+    #[allow(missing_docs)]
+    fn reinterpret_from(from: F) -> Self;
+}
+
+/// Reinterprets the bits of `Self` as a same-width integer of the opposite signedness.
+///
+/// This trait is automatically implemented when `I` implements `ReinterpretFrom<Self>`.
+///
+/// See [`ReinterpretFrom`] for main documentation.
+///
+/// In order to help with type inference,
+/// the [`IntoType`] extension trait provides `.reinterpret_into_type::<T>()` syntax.
+/// ```
+/// use cadd::convert::IntoType;
+///
+/// assert_eq!((-1_i16).reinterpret_into_type::<u16>(), u16::MAX);
+/// ```
+pub trait ReinterpretInto<I>: Sized {
+    #[allow(missing_docs)]
+    fn reinterpret_into(self) -> I;
+}
+
+impl<F, I> ReinterpretInto<I> for F
+where
+    I: ReinterpretFrom<F>,
+{
+    #[inline]
+    fn reinterpret_into(self) -> I {
+        I::reinterpret_from(self)
+    }
+}
+
+macro_rules! impl_reinterpret {
+    ($(($signed:ident, $unsigned:ident),)*) => {
+        $(
+            impl ReinterpretFrom<$signed> for $unsigned {
+                #[inline]
+                fn reinterpret_from(from: $signed) -> Self {
+                    from.cast_unsigned()
+                }
+            }
+
+            impl ReinterpretFrom<$unsigned> for $signed {
+                #[inline]
+                fn reinterpret_from(from: $unsigned) -> Self {
+                    from.cast_signed()
+                }
+            }
+        )*
+    }
+}
+
+impl_reinterpret!(
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+    (i128, u128),
+    (isize, usize),
+);
+
 /// Conversion from an integer type to the corresponding [`NonZero`](std::num::NonZero) type.
 ///
 /// If the value is zero, it returns an error with a backtrace.
@@ -210,6 +427,7 @@ pub trait ToNonZero {
 ///
 /// If the value is zero, it returns an error with a backtrace.
 #[inline]
+#[track_caller]
 pub fn non_zero<T: ToNonZero>(a: T) -> crate::Result<T::NonZero, T::Error> {
     a.to_non_zero()
 }
@@ -221,8 +439,12 @@ macro_rules! impl_to_non_zero {
                 type Error = $crate::Error;
                 type NonZero = ::core::num::NonZero<$ty>;
                 #[inline]
+                #[track_caller]
                 fn to_non_zero(self) -> $crate::Result<Self::NonZero> {
-                    ::core::num::NonZero::new(self).ok_or_else(|| $crate::Error::new("unexpected zero value".into()))
+                    match ::core::num::NonZero::new(self) {
+                        Some(value) => Ok(value),
+                        None => Err($crate::error::error!("convert", "unexpected zero value")),
+                    }
                 }
             }
         )*
@@ -230,3 +452,230 @@ macro_rules! impl_to_non_zero {
 }
 
 impl_to_non_zero!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+/// Conversion from a signed integer to the [`NonZero`](std::num::NonZero) unsigned integer
+/// of the same width containing its absolute value.
+///
+/// Unlike [`ToNonZero`], this doesn't preserve the sign. The absolute value of `MIN` always fits
+/// into the unsigned type, so the only error case is zero.
+/// ```
+/// use cadd::convert::ToNonZeroAbs;
+///
+/// assert_eq!((-5i32).to_non_zero_abs().unwrap().get(), 5u32);
+/// assert_eq!(i32::MIN.to_non_zero_abs().unwrap().get(), 1 << 31);
+/// assert!(0i32.to_non_zero_abs().is_err());
+/// ```
+#[allow(missing_docs)]
+pub trait ToNonZeroAbs {
+    type NonZero;
+    fn to_non_zero_abs(self) -> crate::Result<Self::NonZero>;
+}
+
+macro_rules! impl_to_non_zero_abs {
+    ($(($signed:ident, $unsigned:ident),)*) => {
+        $(
+            impl ToNonZeroAbs for $signed {
+                type NonZero = ::core::num::NonZero<$unsigned>;
+                #[inline]
+                #[track_caller]
+                fn to_non_zero_abs(self) -> crate::Result<Self::NonZero> {
+                    match ::core::num::NonZero::new(self.unsigned_abs()) {
+                        Some(value) => Ok(value),
+                        None => Err(crate::error::error!("convert", "unexpected zero value")),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_to_non_zero_abs!(
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+    (i128, u128),
+    (isize, usize),
+);
+
+/// Constructors of unsigned [`NonZero`](core::num::NonZero) integers from operations on non-zero values.
+///
+/// The product and the sum of non-zero unsigned values are never zero, so the result stays `NonZero`
+/// without a separate zero check, and the only error case is overflow.
+/// ```
+/// use {cadd::convert::NonZeroFromOps, std::num::NonZero};
+///
+/// let a = NonZero::new(6u32).unwrap();
+/// let b = NonZero::new(7u32).unwrap();
+/// let product: NonZero<u32> = NonZero::cfrom_product(a, b).unwrap();
+/// assert_eq!(product.get(), 42);
+/// assert_eq!(NonZero::cfrom_sum(a, b).unwrap().get(), 13);
+/// assert!(NonZero::cfrom_product(NonZero::<u32>::MAX, b).is_err());
+/// ```
+pub trait NonZeroFromOps: Sized {
+    /// Returns `a * b`. Returns an error on overflow.
+    fn cfrom_product(a: Self, b: Self) -> crate::Result<Self>;
+    /// Returns `a + b`. Returns an error on overflow.
+    fn cfrom_sum(a: Self, b: Self) -> crate::Result<Self>;
+}
+
+macro_rules! impl_non_zero_from_ops {
+    ($($t:ty,)*) => {
+        $(
+            impl NonZeroFromOps for ::core::num::NonZero<$t> {
+                #[inline]
+                #[track_caller]
+                fn cfrom_product(a: Self, b: Self) -> crate::Result<Self> {
+                    crate::ops::cmul(a, b)
+                }
+
+                #[inline]
+                #[track_caller]
+                fn cfrom_sum(a: Self, b: Self) -> crate::Result<Self> {
+                    crate::ops::cadd(a, b.get())
+                }
+            }
+        )*
+    }
+}
+
+impl_non_zero_from_ops!(u8, u16, u32, u64, u128, usize,);
+
+/// Reverses the byte order of an integer. Same as [`u32::swap_bytes`],
+/// but usable in generic code.
+#[allow(missing_docs)]
+pub trait SwapBytes {
+    fn swap_bytes(self) -> Self;
+}
+
+/// Converts an integer from native byte order to big endian. Same as [`u32::to_be`],
+/// but usable in generic code.
+/// ```
+/// use cadd::convert::ToBe;
+///
+/// fn to_network_order<T: ToBe + Copy>(values: &mut [T]) {
+///     for value in values {
+///         *value = ToBe::to_be(*value);
+///     }
+/// }
+/// let mut values = [1u16, 2];
+/// to_network_order(&mut values);
+/// assert_eq!(values, [1u16.to_be(), 2u16.to_be()]);
+/// ```
+#[allow(missing_docs)]
+pub trait ToBe {
+    fn to_be(self) -> Self;
+}
+
+/// Converts an integer from native byte order to little endian. Same as [`u32::to_le`],
+/// but usable in generic code.
+#[allow(missing_docs)]
+pub trait ToLe {
+    fn to_le(self) -> Self;
+}
+
+macro_rules! impl_byte_order {
+    ($($ty:ident,)*) => {
+        $(
+            impl $crate::convert::SwapBytes for $ty {
+                #[inline]
+                fn swap_bytes(self) -> Self {
+                    $ty::swap_bytes(self)
+                }
+            }
+
+            impl $crate::convert::ToBe for $ty {
+                #[inline]
+                fn to_be(self) -> Self {
+                    $ty::to_be(self)
+                }
+            }
+
+            impl $crate::convert::ToLe for $ty {
+                #[inline]
+                fn to_le(self) -> Self {
+                    $ty::to_le(self)
+                }
+            }
+        )*
+    }
+}
+
+impl_byte_order!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+/// Converts bytes to a string slice, skipping the UTF-8 byte order mark (`EF BB BF`) if present.
+///
+/// Returns an error if the bytes are not valid UTF-8. Byte positions in the error message
+/// don't include the stripped byte order mark.
+/// ```
+/// use cadd::convert::from_utf8_strip_bom;
+///
+/// assert_eq!(from_utf8_strip_bom(b"\xEF\xBB\xBFkey=1").unwrap(), "key=1");
+/// assert_eq!(from_utf8_strip_bom(b"key=1").unwrap(), "key=1");
+/// assert!(from_utf8_strip_bom(b"\xEF\xBB\xBF\xFF").is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn from_utf8_strip_bom(bytes: &[u8]) -> crate::Result<&str> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    match core::str::from_utf8(bytes) {
+        Ok(value) => Ok(value),
+        Err(err) => Err(crate::error::error!("convert", "not a utf-8 string: {err}")),
+    }
+}
+
+/// Converts a `char` to an ASCII byte, replacing non-ASCII characters with `replacement`.
+///
+/// This conversion is lossy. Use [`Cfrom`] to get an error instead. [`SaturatingFrom`]
+/// does the same with `b'?'` as the replacement.
+/// ```
+/// use cadd::convert::{char_to_ascii_lossy, SaturatingFrom};
+///
+/// assert_eq!(char_to_ascii_lossy('A', b'?'), b'A');
+/// assert_eq!(char_to_ascii_lossy('é', b'?'), b'?');
+/// assert_eq!(u8::saturating_from('é'), b'?');
+/// assert_eq!(u8::saturating_from('€'), b'?');
+/// ```
+#[inline]
+pub fn char_to_ascii_lossy(c: char, replacement: u8) -> u8 {
+    if c.is_ascii() {
+        c as u8
+    } else {
+        replacement
+    }
+}
+
+/// Encodes a `char` as UTF-8 into the provided buffer.
+///
+/// Same as [`char::encode_utf8`], but returns an error instead of panicking if the buffer is too small.
+/// ```
+/// use cadd::convert::CencodeUtf8;
+///
+/// let mut buf = [0; 4];
+/// assert_eq!('€'.cencode_utf8(&mut buf).unwrap(), "€");
+/// assert_eq!(
+///     '€'.cencode_utf8(&mut buf[..1]).unwrap_err().message(),
+///     "buffer is too small to encode '€' as UTF-8: required 3 bytes, available 1",
+/// );
+/// ```
+#[allow(missing_docs)]
+pub trait CencodeUtf8 {
+    fn cencode_utf8(self, buf: &mut [u8]) -> crate::Result<&mut str>;
+}
+
+impl CencodeUtf8 for char {
+    #[inline]
+    #[track_caller]
+    fn cencode_utf8(self, buf: &mut [u8]) -> crate::Result<&mut str> {
+        if buf.len() < self.len_utf8() {
+            return Err(crate::error::error!(
+                "convert",
+                "buffer is too small to encode {:?} as UTF-8: required {} bytes, available {}",
+                self,
+                self.len_utf8(),
+                buf.len(),
+            ));
+        }
+        Ok(self.encode_utf8(buf))
+    }
+}
@@ -1,14 +1,22 @@
-mod array;
+pub(crate) mod array;
+mod bool;
+mod char;
+#[cfg(feature = "duration-parse")]
+mod duration_parse;
+mod float;
+#[cfg(feature = "alloc")]
+pub(crate) mod map;
+mod net;
+mod non_zero_str;
 mod num;
+#[cfg(feature = "num-rational")]
+pub(crate) mod ratio;
+#[cfg(feature = "alloc")]
+pub(crate) mod string;
+mod time;
+mod wrapping;
 
-use {
-    crate::convert::Cfrom,
-    alloc::{ffi::CString, string::String, vec::Vec},
-    core::num::NonZero,
-};
-
-#[cfg(feature = "std")]
-use std::ffi::OsStr;
+use core::num::NonZero;
 
 // delegate to TryFrom
 macro_rules! impl_cfrom {
@@ -17,16 +25,18 @@ macro_rules! impl_cfrom {
             impl $crate::convert::Cfrom<$from> for $to {
                 type Error = $crate::Error;
                 #[inline]
+                #[track_caller]
                 fn cfrom(from: $from) -> $crate::Result<Self> {
-                    ::core::convert::TryFrom::try_from(from)
-                        .map_err(|_| $crate::Error::new(
-                            ::alloc::format!(
-                                "cannot convert value {:?} from {} to {}: value is out of bounds",
-                                from,
-                                ::core::any::type_name::<$from>(),
-                                ::core::any::type_name::<$to>(),
-                            )
-                        ))
+                    match ::core::convert::TryFrom::try_from(from) {
+                        Ok(value) => Ok(value),
+                        Err(_) => Err($crate::error::error!(
+                            "convert",
+                            "cannot convert value {:?} from {} to {}: value is out of bounds",
+                            from,
+                            ::core::any::type_name::<$from>(),
+                            ::core::any::type_name::<$to>(),
+                        )),
+                    }
                 }
             }
         )*
@@ -56,31 +66,3 @@ impl_cfrom!(
 
 // TODO: float to/from int?
 // TODO: float to/from bool?
-
-impl Cfrom<CString> for String {
-    type Error = crate::Error;
-    #[inline]
-    fn cfrom(from: CString) -> crate::Result<Self> {
-        from.try_into()
-            .map_err(|from| crate::Error::new(alloc::format!("not a utf-8 string: {from:?}")))
-    }
-}
-
-impl Cfrom<Vec<u8>> for String {
-    type Error = crate::Error;
-    #[inline]
-    fn cfrom(from: Vec<u8>) -> crate::Result<Self> {
-        from.try_into()
-            .map_err(|from| crate::Error::new(alloc::format!("not a utf-8 string: {from:?}")))
-    }
-}
-
-#[cfg(feature = "std")]
-impl<'a> Cfrom<&'a OsStr> for &'a str {
-    type Error = crate::Error;
-    #[inline]
-    fn cfrom(from: &'a OsStr) -> crate::Result<Self> {
-        from.try_into()
-            .map_err(|err| crate::Error::new(alloc::format!("not a utf-8 string: {from:?}: {err}")))
-    }
-}
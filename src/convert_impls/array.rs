@@ -1,10 +1,11 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
 use {
-    crate::convert::Cfrom,
-    alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec},
+    crate::{convert::Cfrom, error::error},
     core::fmt::Debug,
 };
 
-struct SliceLimitedDebug<'a, T>(&'a [T]);
+pub(crate) struct SliceLimitedDebug<'a, T>(pub(crate) &'a [T]);
 
 impl<'a, T: Debug> Debug for SliceLimitedDebug<'a, T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -30,8 +31,12 @@ impl<'a, T: Debug, const N: usize> Cfrom<&'a [T]> for &'a [T; N] {
     type Error = crate::Error;
 
     #[inline]
+    #[track_caller]
     fn cfrom(from: &'a [T]) -> Result<Self, Self::Error> {
-        from.try_into().map_err(|_| slice_to_array_error(N, from))
+        match from.try_into() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(slice_to_array_error(N, from)),
+        }
     }
 }
 
@@ -39,6 +44,7 @@ impl<'a, T: Debug, const N: usize> Cfrom<&'a mut [T]> for &'a mut [T; N] {
     type Error = crate::Error;
 
     #[inline]
+    #[track_caller]
     fn cfrom(from: &'a mut [T]) -> Result<Self, Self::Error> {
         // We have to do it with an extra check because of borrow checker.
         if from.len() == N {
@@ -53,8 +59,12 @@ impl<'a, T: Copy + Debug, const N: usize> Cfrom<&'a [T]> for [T; N] {
     type Error = crate::Error;
 
     #[inline]
+    #[track_caller]
     fn cfrom(from: &'a [T]) -> Result<Self, Self::Error> {
-        from.try_into().map_err(|_| slice_to_array_error(N, from))
+        match from.try_into() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(slice_to_array_error(N, from)),
+        }
     }
 }
 
@@ -62,11 +72,16 @@ impl<'a, T: Copy + Debug, const N: usize> Cfrom<&'a mut [T]> for [T; N] {
     type Error = crate::Error;
 
     #[inline]
+    #[track_caller]
     fn cfrom(from: &'a mut [T]) -> Result<Self, Self::Error> {
-        from.try_into().map_err(|_| slice_to_array_error(N, from))
+        match from.try_into() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(slice_to_array_error(N, from)),
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
 macro_rules! impl_cfrom_owned_to_array {
     ($(($from:ty, $to:ty),)*) => {
         $(
@@ -74,6 +89,7 @@ macro_rules! impl_cfrom_owned_to_array {
                 type Error = crate::Error;
 
                 #[inline]
+                #[track_caller]
                 fn cfrom(from: $from) -> Result<Self, Self::Error> {
                     if from.len() == N {
                         Ok(from.try_into().unwrap())
@@ -86,15 +102,18 @@ macro_rules! impl_cfrom_owned_to_array {
     };
 }
 
+#[track_caller]
 fn slice_to_array_error<T: Debug>(target_len: usize, value: &[T]) -> crate::Error {
-    crate::Error::new(alloc::format!(
+    error!(
+        "convert",
         "expected slice of length {}, got length {}: {:?}",
         target_len,
         value.len(),
         SliceLimitedDebug(value),
-    ))
+    )
 }
 
+#[cfg(feature = "alloc")]
 impl_cfrom_owned_to_array!(
     (Rc<[T]>, Rc<[T; N]>),
     (Arc<[T]>, Arc<[T; N]>),
@@ -102,3 +121,31 @@ impl_cfrom_owned_to_array!(
     (Box<[T]>, Box<[T; N]>),
     (Vec<T>, Box<[T; N]>),
 );
+
+/// Converts a `Vec` to an array, padding it with default values if it's too short.
+///
+/// Unlike the exact conversion (`Vec<T>` to `[T; N]` via [`Cfrom`]), a shorter vector is accepted
+/// and the missing elements are filled with `T::default()`. A longer vector is still an error because
+/// dropping the extra elements would silently lose data.
+/// ```
+/// use cadd::convert::array_from_vec_padded;
+///
+/// assert_eq!(array_from_vec_padded::<u8, 4>(vec![1, 2]).unwrap(), [1, 2, 0, 0]);
+/// assert_eq!(array_from_vec_padded::<u8, 2>(vec![1, 2]).unwrap(), [1, 2]);
+/// assert!(array_from_vec_padded::<u8, 2>(vec![1, 2, 3]).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+#[track_caller]
+pub fn array_from_vec_padded<T: Default, const N: usize>(v: Vec<T>) -> crate::Result<[T; N]> {
+    if v.len() > N {
+        return Err(error!(
+            "convert",
+            "expected at most {} elements, got {}",
+            N,
+            v.len(),
+        ));
+    }
+    let mut items = v.into_iter();
+    Ok(core::array::from_fn(|_| items.next().unwrap_or_default()))
+}
@@ -0,0 +1,17 @@
+use crate::{
+    convert::Cfrom,
+    parse::{parse_bool_with, BoolTokens},
+};
+
+/// Accepts `true`/`false`, `yes`/`no`, `on`/`off` and `1`/`0` (case-insensitive).
+///
+/// See [`parse_bool_with`] for custom tokens.
+impl Cfrom<&str> for bool {
+    type Error = crate::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: &str) -> crate::Result<Self> {
+        parse_bool_with(from, &BoolTokens::DEFAULT)
+    }
+}
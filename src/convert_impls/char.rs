@@ -0,0 +1,15 @@
+use crate::convert::SaturatingFrom;
+
+/// Lossy: ASCII characters are kept, and all other characters are replaced with `b'?'`.
+///
+/// See [`char_to_ascii_lossy`](crate::convert::char_to_ascii_lossy) for a custom replacement.
+impl SaturatingFrom<char> for u8 {
+    #[inline]
+    fn saturating_from(from: char) -> Self {
+        if from.is_ascii() {
+            from as u8
+        } else {
+            b'?'
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use {
+    crate::{convert::Cfrom, error::error},
+    core::time::Duration,
+};
+
+type UnitToDuration = fn(u64) -> Option<Duration>;
+
+// Ordered from the largest to the smallest.
+const UNITS: &[(&str, UnitToDuration)] = &[
+    ("d", |n| n.checked_mul(86_400).map(Duration::from_secs)),
+    ("h", |n| n.checked_mul(3_600).map(Duration::from_secs)),
+    ("m", |n| n.checked_mul(60).map(Duration::from_secs)),
+    ("s", |n| Some(Duration::from_secs(n))),
+    ("ms", |n| Some(Duration::from_millis(n))),
+    ("us", |n| Some(Duration::from_micros(n))),
+    ("ns", |n| Some(Duration::from_nanos(n))),
+];
+
+/// Parses a duration such as `"1h30m"` or `"2s500ms"`.
+///
+/// The string must consist of one or more segments without any separators. Each segment is a decimal number
+/// followed by a unit: `d` (days), `h` (hours), `m` (minutes), `s` (seconds), `ms` (milliseconds),
+/// `us` (microseconds) or `ns` (nanoseconds). Units must go from the largest to the smallest,
+/// and each unit can be used at most once.
+///
+/// Returns an error with the position (in bytes) of the invalid segment if the string doesn't match this grammar,
+/// or if the result doesn't fit into `Duration`.
+///
+/// Requires the `duration-parse` feature.
+impl Cfrom<&str> for Duration {
+    type Error = crate::Error;
+
+    #[track_caller]
+    fn cfrom(from: &str) -> crate::Result<Self> {
+        if from.is_empty() {
+            return Err(error!("parse", "invalid duration {from:?}: empty string"));
+        }
+        let mut total = Duration::ZERO;
+        let mut pos = 0;
+        let mut min_unit_index = 0;
+        while pos < from.len() {
+            let rest = &from[pos..];
+            let digits_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+            if digits_len == 0 {
+                return Err(error!(
+                    "parse",
+                    "invalid duration {from:?}: expected a number at position {pos}"
+                ));
+            }
+            let Ok(number) = rest[..digits_len].parse::<u64>() else {
+                return Err(error!(
+                    "parse",
+                    "invalid duration {from:?}: number at position {pos} is too large"
+                ));
+            };
+            let unit_pos = pos + digits_len;
+            let unit_len = from[unit_pos..]
+                .bytes()
+                .take_while(u8::is_ascii_alphabetic)
+                .count();
+            let unit = &from[unit_pos..unit_pos + unit_len];
+            let Some(unit_index) = UNITS.iter().position(|(name, _)| *name == unit) else {
+                return Err(error!(
+                    "parse",
+                    "invalid duration {from:?}: expected a unit at position {unit_pos}, got {unit:?}"
+                ));
+            };
+            if unit_index < min_unit_index {
+                return Err(error!(
+                    "parse",
+                    "invalid duration {from:?}: unit {unit:?} at position {unit_pos} is out of order"
+                ));
+            }
+            let Some(value) =
+                UNITS[unit_index].1(number).and_then(|value| total.checked_add(value))
+            else {
+                return Err(error!(
+                    "parse",
+                    "invalid duration {from:?}: overflow at position {pos}"
+                ));
+            };
+            total = value;
+            min_unit_index = unit_index + 1;
+            pos = unit_pos + unit_len;
+        }
+        Ok(total)
+    }
+}
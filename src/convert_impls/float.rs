@@ -0,0 +1,51 @@
+use crate::{
+    convert::{Cfrom, SaturatingFrom},
+    error::error,
+};
+
+// Unlike `as` casts, these conversions never produce infinity. Note that the range of `f64` is much larger
+// than the range of 128-bit integers, so only conversions to `f32` can actually overflow.
+macro_rules! impl_int_to_float {
+    ($($source:ty => $($target:ty),+;)*) => {$($(
+        /// Returns `MAX` or `MIN` of the target type instead of infinity if the value is too large.
+        impl SaturatingFrom<$source> for $target {
+            #[inline]
+            fn saturating_from(from: $source) -> Self {
+                (from as $target).clamp(<$target>::MIN, <$target>::MAX)
+            }
+        }
+
+        /// Returns an error if the value is too large or can't be represented exactly.
+        impl Cfrom<$source> for $target {
+            type Error = crate::Error;
+
+            #[inline]
+            #[track_caller]
+            fn cfrom(from: $source) -> crate::Result<Self> {
+                let value = from as $target;
+                let reason = if value.is_infinite() {
+                    "value is out of bounds"
+                // `MAX` of the source type is rounded up to a power of two that is out of bounds
+                // of the source type, but the cast back saturates to `MAX`.
+                } else if value == <$source>::MAX as $target || value as $source != from {
+                    "value cannot be represented exactly"
+                } else {
+                    return Ok(value);
+                };
+                Err(error!(
+                    "convert",
+                    "cannot convert value {} from {} to {}: {}",
+                    from,
+                    ::core::any::type_name::<$source>(),
+                    ::core::any::type_name::<$target>(),
+                    reason,
+                ))
+            }
+        }
+    )*)*};
+}
+
+impl_int_to_float!(
+    u128 => f32, f64;
+    i128 => f32, f64;
+);
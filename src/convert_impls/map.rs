@@ -0,0 +1,46 @@
+use {
+    crate::{convert::Cinto, error::error},
+    alloc::collections::BTreeMap,
+    core::fmt::Debug,
+};
+
+/// Converts all values of a map with [`Cinto`], keeping the keys unchanged.
+///
+/// On failure, the error message contains the key of the value that couldn't be converted.
+/// ```
+/// use {cadd::convert::map_values_cinto, std::collections::BTreeMap};
+///
+/// let map = BTreeMap::from([("a", 1u32), ("b", 2)]);
+/// assert_eq!(map_values_cinto::<_, _, u8>(map).unwrap(), BTreeMap::from([("a", 1u8), ("b", 2)]));
+///
+/// let map = BTreeMap::from([("a", 1u32), ("b", 300)]);
+/// assert_eq!(
+///     map_values_cinto::<_, _, u8>(map).unwrap_err().message(),
+///     r#"cannot convert value for key "b": cannot convert value 300 from u32 to u8: value is out of bounds"#,
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn map_values_cinto<K, V1, V2>(map: BTreeMap<K, V1>) -> crate::Result<BTreeMap<K, V2>>
+where
+    K: Ord + Debug,
+    V1: Cinto<V2, Error = crate::Error>,
+{
+    let mut output = BTreeMap::new();
+    for (key, value) in map {
+        match value.cinto() {
+            Ok(value) => {
+                output.insert(key, value);
+            }
+            Err(err) => {
+                return Err(error!(
+                    "convert",
+                    "cannot convert value for key {:?}: {}",
+                    key,
+                    err.message(),
+                ))
+            }
+        }
+    }
+    Ok(output)
+}
@@ -0,0 +1,41 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use {
+    crate::{convert::Cfrom, error::error},
+    core::net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+/// Creates an IPv4 address from 4 bytes or an IPv6 address from 16 bytes (in network byte order).
+impl Cfrom<&[u8]> for IpAddr {
+    type Error = crate::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: &[u8]) -> crate::Result<Self> {
+        if let Ok(octets) = <[u8; 4]>::try_from(from) {
+            Ok(IpAddr::V4(Ipv4Addr::from(octets)))
+        } else if let Ok(octets) = <[u8; 16]>::try_from(from) {
+            Ok(IpAddr::V6(Ipv6Addr::from(octets)))
+        } else {
+            Err(error!(
+                "convert",
+                "cannot convert bytes to IpAddr: expected 4 or 16 bytes, got {}",
+                from.len(),
+            ))
+        }
+    }
+}
+
+/// Returns the octets of the address (4 for IPv4, 16 for IPv6). Never fails.
+#[cfg(feature = "alloc")]
+impl Cfrom<IpAddr> for Vec<u8> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn cfrom(from: IpAddr) -> crate::Result<Self> {
+        Ok(match from {
+            IpAddr::V4(addr) => addr.octets().into(),
+            IpAddr::V6(addr) => addr.octets().into(),
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use {
+    crate::{convert::Cfrom, error::error},
+    core::num::{IntErrorKind, NonZero},
+};
+
+macro_rules! impl_non_zero_cfrom_str {
+    ($($t:ty,)*) => {
+        $(
+            /// Parses a positive integer. The error message says whether the string is not a number,
+            /// the number is out of bounds or the number is zero.
+            impl Cfrom<&str> for NonZero<$t> {
+                type Error = crate::Error;
+
+                #[inline]
+                #[track_caller]
+                fn cfrom(from: &str) -> crate::Result<Self> {
+                    let reason = match from.parse::<NonZero<$t>>() {
+                        Ok(value) => return Ok(value),
+                        Err(err) => match err.kind() {
+                            IntErrorKind::Zero => "zero is not allowed",
+                            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                                "number is out of bounds"
+                            }
+                            _ => "not a number",
+                        },
+                    };
+                    Err(error!(
+                        "parse",
+                        "cannot parse {:?} as NonZero<{}>: {}",
+                        from,
+                        stringify!($t),
+                        reason,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_cfrom_str!(u8, u16, u32, u64, u128, usize,);
@@ -46,6 +46,7 @@ macro_rules! impl_cfrom_unbounded {
         impl $crate::convert::Cfrom<$source> for $target {
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn cfrom(u: $source) -> $crate::Result<Self> {
                 Ok(u as Self)
             }
@@ -66,17 +67,17 @@ macro_rules! impl_cfrom_lower_bounded {
         impl $crate::convert::Cfrom<$source> for $target {
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn cfrom(u: $source) -> $crate::Result<Self> {
                 if u >= 0 {
                     Ok(u as Self)
                 } else {
-                    Err($crate::Error::new(
-                        ::alloc::format!(
-                            "cannot convert value {:?} from {} to {}: value is out of bounds",
-                            u,
-                            ::core::any::type_name::<$source>(),
-                            ::core::any::type_name::<$target>(),
-                        )
+                    Err($crate::error::error!(
+                        "convert",
+                        "cannot convert value {:?} from {} to {}: value is out of bounds",
+                        u,
+                        ::core::any::type_name::<$source>(),
+                        ::core::any::type_name::<$target>(),
                     ))
                 }
             }
@@ -101,15 +102,15 @@ macro_rules! impl_cfrom_upper_bounded {
         impl $crate::convert::Cfrom<$source> for $target {
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn cfrom(u: $source) -> $crate::Result<Self> {
                 if u > (Self::MAX as $source) {
-                    Err($crate::Error::new(
-                        ::alloc::format!(
-                            "cannot convert value {:?} from {} to {}: value is out of bounds",
-                            u,
-                            ::core::any::type_name::<$source>(),
-                            ::core::any::type_name::<$target>(),
-                        )
+                    Err($crate::error::error!(
+                        "convert",
+                        "cannot convert value {:?} from {} to {}: value is out of bounds",
+                        u,
+                        ::core::any::type_name::<$source>(),
+                        ::core::any::type_name::<$target>(),
                     ))
                 } else {
                     Ok(u as Self)
@@ -136,17 +137,17 @@ macro_rules! impl_cfrom_both_bounded {
         impl $crate::convert::Cfrom<$source> for $target {
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn cfrom(u: $source) -> $crate::Result<Self> {
                 let min = Self::MIN as $source;
                 let max = Self::MAX as $source;
                 if u < min || u > max {
-                    Err($crate::Error::new(
-                        ::alloc::format!(
-                            "cannot convert value {:?} from {} to {}: value is out of bounds",
-                            u,
-                            ::core::any::type_name::<$source>(),
-                            ::core::any::type_name::<$target>(),
-                        )
+                    Err($crate::error::error!(
+                        "convert",
+                        "cannot convert value {:?} from {} to {}: value is out of bounds",
+                        u,
+                        ::core::any::type_name::<$source>(),
+                        ::core::any::type_name::<$target>(),
                     ))
                 } else {
                     Ok(u as Self)
@@ -211,10 +212,43 @@ impl_cfrom_lower_bounded!(i128 => u128);
 impl_cfrom_upper_bounded!(usize => isize);
 impl_cfrom_lower_bounded!(isize => usize);
 
+// Lossless conversions (covered by `From` in `std`). They are needed for platform-dependent
+// type aliases like `c_long`, which may or may not be the same type as the other side of the conversion.
+
+// identity
+impl_cfrom_unbounded!(u8 => u8);
+impl_cfrom_unbounded!(u16 => u16);
+impl_cfrom_unbounded!(u32 => u32);
+impl_cfrom_unbounded!(u64 => u64);
+impl_cfrom_unbounded!(u128 => u128);
+impl_cfrom_unbounded!(usize => usize);
+impl_cfrom_unbounded!(i8 => i8);
+impl_cfrom_unbounded!(i16 => i16);
+impl_cfrom_unbounded!(i32 => i32);
+impl_cfrom_unbounded!(i64 => i64);
+impl_cfrom_unbounded!(i128 => i128);
+impl_cfrom_unbounded!(isize => isize);
+
+// unsigned integer -> wider unsigned integer
+impl_cfrom_unbounded!(u8 => u16, u32, u64, u128, usize);
+impl_cfrom_unbounded!(u16 => u32, u64, u128, usize);
+impl_cfrom_unbounded!(u32 => u64, u128);
+impl_cfrom_unbounded!(u64 => u128);
+
+// signed integer -> wider signed integer
+impl_cfrom_unbounded!(i8 => i16, i32, i64, i128, isize);
+impl_cfrom_unbounded!(i16 => i32, i64, i128, isize);
+impl_cfrom_unbounded!(i32 => i64, i128);
+impl_cfrom_unbounded!(i64 => i128);
+
+// unsigned integer -> wider signed integer
+impl_cfrom_unbounded!(u8 => i16, i32, i64, i128, isize);
+impl_cfrom_unbounded!(u16 => i32, i64, i128);
+impl_cfrom_unbounded!(u32 => i64, i128);
+impl_cfrom_unbounded!(u64 => i128);
+
 #[cfg(target_pointer_width = "16")]
 mod ptr_try_from_impls {
-    use super::TryFromIntError;
-
     impl_cfrom_upper_bounded!(usize => u8);
     impl_cfrom_unbounded!(usize => u16, u32, u64, u128);
     impl_cfrom_upper_bounded!(usize => i8, i16);
@@ -235,8 +269,6 @@ mod ptr_try_from_impls {
 
 #[cfg(target_pointer_width = "32")]
 mod ptr_try_from_impls {
-    use super::TryFromIntError;
-
     impl_cfrom_upper_bounded!(usize => u8, u16);
     impl_cfrom_unbounded!(usize => u32, u64, u128);
     impl_cfrom_upper_bounded!(usize => i8, i16, i32);
@@ -280,3 +312,26 @@ mod ptr_try_from_impls {
     rev!(impl_cfrom_unbounded, isize => i32, i64);
     rev!(impl_cfrom_both_bounded, isize => i128);
 }
+
+macro_rules! impl_saturating_from_for_non_zero {
+    ($($target:ty),+) => {$(
+        /// Clamps the value to the range of the target integer type
+        /// (see [`SaturatingFrom`](crate::convert::SaturatingFrom)), then replaces zero with one.
+        /// Consequently, negative values become one for unsigned targets.
+        impl<F> $crate::convert::SaturatingFrom<F> for NonZero<$target>
+        where
+            $target: $crate::convert::SaturatingFrom<F>,
+        {
+            #[inline]
+            fn saturating_from(from: F) -> Self {
+                match NonZero::new(<$target>::saturating_from(from)) {
+                    Some(value) => value,
+                    None => NonZero::<$target>::new(1).unwrap(),
+                }
+            }
+        }
+    )*}
+}
+
+impl_saturating_from_for_non_zero!(u8, u16, u32, u64, u128, usize);
+impl_saturating_from_for_non_zero!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,93 @@
+use {
+    crate::{convert::Cfrom, error::error},
+    num_rational::Ratio,
+};
+
+/// Returns a rational approximation of `x`: the last continued-fraction convergent
+/// with denominator ≤ `max_denom` (and a numerator that fits into `i64`).
+///
+/// This is not always the closest fraction with such a denominator: a semiconvergent between
+/// the last two convergents may be closer. Returns an error if `x` is NaN or infinite,
+/// if `x` is out of bounds of `i64`, or if `max_denom` is less than 1.
+///
+/// Requires the `num-rational` feature.
+/// ```
+/// use {cadd::convert::ratio_from_f64, num_rational::Ratio};
+///
+/// assert_eq!(ratio_from_f64(0.75, 100).unwrap(), Ratio::new(3, 4));
+/// assert_eq!(ratio_from_f64(-0.75, 100).unwrap(), Ratio::new(-3, 4));
+/// assert_eq!(ratio_from_f64(std::f64::consts::PI, 1000).unwrap(), Ratio::new(355, 113));
+/// assert!(ratio_from_f64(f64::NAN, 1000).is_err());
+/// ```
+#[track_caller]
+pub fn ratio_from_f64(x: f64, max_denom: i64) -> crate::Result<Ratio<i64>> {
+    if !x.is_finite() {
+        return Err(error!(
+            "convert",
+            "cannot convert {x:?} to a ratio: value is not finite"
+        ));
+    }
+    if max_denom < 1 {
+        return Err(error!(
+            "convert",
+            "cannot convert {x:?} to a ratio: max denominator {max_denom} is less than 1"
+        ));
+    }
+    // `i64::MAX as f64` is 2^63 which is out of bounds of `i64`.
+    if x.abs() >= i64::MAX as f64 {
+        return Err(error!(
+            "convert",
+            "cannot convert {x:?} to Ratio<i64>: value is out of bounds"
+        ));
+    }
+
+    // Convergents h/k of the continued fraction, starting with h_{-1}/k_{-1} = 1/0 and h_{-2}/k_{-2} = 0/1.
+    let (mut h, mut h_prev) = (1_i128, 0_i128);
+    let (mut k, mut k_prev) = (0_i128, 1_i128);
+    let mut y = x.abs();
+    loop {
+        let a = y.floor();
+        let Some((h_next, k_next)) = next_convergent(a as i128, (h, h_prev), (k, k_prev)) else {
+            break;
+        };
+        if h_next > i128::from(i64::MAX) || k_next > i128::from(max_denom) {
+            break;
+        }
+        (h, h_prev) = (h_next, h);
+        (k, k_prev) = (k_next, k);
+        let fraction = y - a;
+        if fraction == 0.0 {
+            break;
+        }
+        y = 1.0 / fraction;
+    }
+
+    // The first convergent always fits because `|x| < i64::MAX` and `max_denom >= 1`.
+    let h = i64::try_from(h).expect("numerator fits into i64");
+    let k = i64::try_from(k).expect("denominator fits into i64");
+    Ok(Ratio::new_raw(if x < 0.0 { -h } else { h }, k))
+}
+
+fn next_convergent(
+    a: i128,
+    (h, h_prev): (i128, i128),
+    (k, k_prev): (i128, i128),
+) -> Option<(i128, i128)> {
+    let h_next = a.checked_mul(h)?.checked_add(h_prev)?;
+    let k_next = a.checked_mul(k)?.checked_add(k_prev)?;
+    Some((h_next, k_next))
+}
+
+/// Returns the last continued-fraction convergent of the value that fits into `Ratio<i64>`.
+/// See [`ratio_from_f64`](crate::convert::ratio_from_f64).
+///
+/// Requires the `num-rational` feature.
+impl Cfrom<f64> for Ratio<i64> {
+    type Error = crate::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: f64) -> crate::Result<Self> {
+        ratio_from_f64(from, i64::MAX)
+    }
+}
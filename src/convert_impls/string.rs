@@ -0,0 +1,88 @@
+#[cfg(feature = "std")]
+use std::ffi::OsStr;
+use {
+    crate::{convert::Cfrom, error::error},
+    alloc::{ffi::CString, string::String, vec::Vec},
+};
+
+impl Cfrom<CString> for String {
+    type Error = crate::Error;
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: CString) -> crate::Result<Self> {
+        match from.try_into() {
+            Ok(value) => Ok(value),
+            Err(from) => {
+                let err = error!("convert", "not a utf-8 string: {from:?}");
+                #[cfg(feature = "source-value")]
+                let err = err.with_source_value(from.into_cstring());
+                Err(err)
+            }
+        }
+    }
+}
+
+impl Cfrom<Vec<u8>> for String {
+    type Error = crate::Error;
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: Vec<u8>) -> crate::Result<Self> {
+        match from.try_into() {
+            Ok(value) => Ok(value),
+            Err(from) => {
+                let err = error!("convert", "not a utf-8 string: {from:?}");
+                #[cfg(feature = "source-value")]
+                let err = err.with_source_value(from.into_bytes());
+                Err(err)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Cfrom<&'a OsStr> for &'a str {
+    type Error = crate::Error;
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: &'a OsStr) -> crate::Result<Self> {
+        match from.try_into() {
+            Ok(value) => Ok(value),
+            Err(err) => Err(error!("convert", "not a utf-8 string: {from:?}: {err}")),
+        }
+    }
+}
+
+/// Returns an error instead of panicking or aborting if the total UTF-8 length exceeds `isize::MAX`
+/// or if the allocation fails.
+impl Cfrom<&[char]> for String {
+    type Error = crate::Error;
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: &[char]) -> crate::Result<Self> {
+        let Some(len) = total_len(from.iter().map(|c| c.len_utf8())) else {
+            return Err(error!(
+                "convert",
+                "string length overflow: {} chars",
+                from.len()
+            ));
+        };
+        let mut value = String::new();
+        if let Err(err) = value.try_reserve_exact(len) {
+            return Err(error!(
+                "convert",
+                "cannot allocate string of length {len}: {err}"
+            ));
+        }
+        value.extend(from);
+        Ok(value)
+    }
+}
+
+/// Sum of `lengths`, or `None` if it overflows or exceeds `isize::MAX`.
+pub(crate) fn total_len(lengths: impl IntoIterator<Item = usize>) -> Option<usize> {
+    let mut total = 0usize;
+    for len in lengths {
+        total = total.checked_add(len)?;
+    }
+    (total <= isize::MAX as usize).then_some(total)
+}
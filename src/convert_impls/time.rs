@@ -0,0 +1,94 @@
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+use {
+    crate::{convert::Cfrom, error::error},
+    core::time::Duration,
+};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Same as `Duration::new` but returns an error instead of panicking on overflow.
+impl Cfrom<(u64, u32)> for Duration {
+    type Error = crate::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom((secs, nanos): (u64, u32)) -> crate::Result<Self> {
+        let Some(total_secs) = secs.checked_add(u64::from(nanos / NANOS_PER_SEC)) else {
+            return Err(error!(
+                "convert",
+                "cannot convert ({secs}, {nanos}) to Duration: value is out of bounds"
+            ));
+        };
+        Ok(Duration::new(total_secs, nanos % NANOS_PER_SEC))
+    }
+}
+
+/// Returns `(as_secs, subsec_nanos)`.
+impl Cfrom<Duration> for (u64, u32) {
+    type Error = crate::Error;
+
+    #[inline]
+    fn cfrom(from: Duration) -> crate::Result<Self> {
+        Ok((from.as_secs(), from.subsec_nanos()))
+    }
+}
+
+/// Interprets the value as a UNIX timestamp: the number of seconds since the UNIX epoch.
+/// Negative values are before the epoch.
+///
+/// Returns an error if the time is not representable by `SystemTime` on the current platform.
+#[cfg(feature = "std")]
+impl Cfrom<i64> for SystemTime {
+    type Error = crate::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: i64) -> crate::Result<Self> {
+        let offset = Duration::from_secs(from.unsigned_abs());
+        let time = if from >= 0 {
+            UNIX_EPOCH.checked_add(offset)
+        } else {
+            UNIX_EPOCH.checked_sub(offset)
+        };
+        match time {
+            Some(time) => Ok(time),
+            None => Err(error!(
+                "convert",
+                "cannot convert UNIX timestamp {} to SystemTime: value is out of bounds", from,
+            )),
+        }
+    }
+}
+
+/// Returns the UNIX timestamp: the number of seconds since the UNIX epoch, rounded down.
+/// Times before the epoch produce negative values.
+///
+/// Returns an error if the number of seconds doesn't fit into `i64`.
+#[cfg(feature = "std")]
+impl Cfrom<SystemTime> for i64 {
+    type Error = crate::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: SystemTime) -> crate::Result<Self> {
+        let secs = match from.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_secs()).ok(),
+            Err(err) => {
+                let before = err.duration();
+                // Rounded down, so a fractional second adds one more second before the epoch.
+                before
+                    .as_secs()
+                    .checked_add(u64::from(before.subsec_nanos() > 0))
+                    .and_then(|secs| 0i64.checked_sub_unsigned(secs))
+            }
+        };
+        match secs {
+            Some(secs) => Ok(secs),
+            None => Err(error!(
+                "convert",
+                "cannot convert {:?} to UNIX timestamp: value is out of bounds", from,
+            )),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use {
+    crate::convert::{Cfrom, SaturatingFrom},
+    core::{
+        cmp::Reverse,
+        num::{Saturating, Wrapping},
+    },
+};
+
+/// Keeps the value and only changes the arithmetic policy. Never fails.
+impl<T> Cfrom<Wrapping<T>> for Saturating<T> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn cfrom(from: Wrapping<T>) -> crate::Result<Self> {
+        Ok(Saturating(from.0))
+    }
+}
+
+/// Keeps the value and only changes the arithmetic policy. Never fails.
+impl<T> Cfrom<Saturating<T>> for Wrapping<T> {
+    type Error = crate::Error;
+
+    #[inline]
+    fn cfrom(from: Saturating<T>) -> crate::Result<Self> {
+        Ok(Wrapping(from.0))
+    }
+}
+
+/// Converts the wrapped value.
+impl<A, B: Cfrom<A>> Cfrom<Reverse<A>> for Reverse<B> {
+    type Error = B::Error;
+
+    #[inline]
+    #[track_caller]
+    fn cfrom(from: Reverse<A>) -> Result<Self, Self::Error> {
+        match B::cfrom(from.0) {
+            Ok(value) => Ok(Reverse(value)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Converts the wrapped value. The value is clamped the same way as without the wrapper.
+impl<A, B: SaturatingFrom<A>> SaturatingFrom<Reverse<A>> for Reverse<B> {
+    #[inline]
+    fn saturating_from(from: Reverse<A>) -> Self {
+        Reverse(B::saturating_from(from.0))
+    }
+}
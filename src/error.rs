@@ -1,57 +1,742 @@
 #[cfg(feature = "std")]
-use std::backtrace::{Backtrace, BacktraceStatus};
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    sync::{atomic::AtomicBool, OnceLock, PoisonError, RwLock},
+};
 
+#[cfg(feature = "source-value")]
+use core::any::Any;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    panic::Location,
+};
+#[cfg(feature = "alloc")]
 use {
-    alloc::{boxed::Box, string::String},
-    core::fmt::{self, Debug, Display, Formatter},
+    alloc::{boxed::Box, string::String, vec::Vec},
+    core::sync::atomic::{AtomicUsize, Ordering},
 };
 
 /// A general error with a message and a backtrace (if enabled).
-pub struct Error(Box<ErrorInner>);
+///
+/// If the `alloc` feature is disabled, the message is stored in a fixed-capacity inline buffer
+/// and is truncated if it's longer than [`Error::INLINE_CAPACITY`] bytes. Otherwise, the message
+/// is truncated if it's longer than the limit configured by [`set_max_message_len`](crate::set_max_message_len).
+///
+/// `Error` is `Send + Sync + 'static` with any set of features, so it can be stored in
+/// error types that are sent between threads or held across `.await` points.
+pub struct Error(
+    #[cfg(feature = "alloc")] Box<ErrorInner>,
+    #[cfg(not(feature = "alloc"))] ErrorInner,
+);
 
 struct ErrorInner {
-    message: String,
+    message: Message,
+    category: &'static str,
+    location: &'static Location<'static>,
+    retryable: bool,
+    #[cfg(feature = "std")]
+    lazy_message: Option<LazyMessage>,
+    #[cfg(feature = "source-value")]
+    source_value: Option<Box<dyn Any + Send + Sync>>,
+    #[cfg(feature = "alloc")]
+    fields: Vec<(&'static str, String)>,
     #[cfg(feature = "std")]
     backtrace: Backtrace,
 }
 
+// `Error` must stay `Send + Sync + 'static`. Values stored in it (e.g. the lazy message
+// and the source value) are required to be `Send + Sync` for this reason.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<Error>();
+};
+
+/// Message created by [`Error::from_fn`]. It's formatted into `cache` when it's first requested as `&str`.
+#[cfg(feature = "std")]
+struct LazyMessage {
+    format: Box<MessageFn>,
+    cache: OnceLock<String>,
+}
+
+#[cfg(feature = "std")]
+impl Display for LazyMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.format)(f)
+    }
+}
+
+#[cfg(feature = "std")]
+type MessageFn = dyn Fn(&mut Formatter<'_>) -> fmt::Result + Send + Sync;
+
+#[cfg(feature = "alloc")]
+type Message = String;
+
+#[cfg(not(feature = "alloc"))]
+type Message = InlineString;
+
 impl Error {
+    /// Maximum length of the message (in bytes) if the `alloc` feature is disabled.
+    ///
+    /// A longer message is truncated on a char boundary, and its end is replaced with `…`
+    /// to indicate that it was cut.
+    ///
+    /// Without `alloc`, the buffer is stored in `Error` itself rather than on the heap,
+    /// so `Error` (and any `cadd::Result<T>`) takes about 176 bytes on 64-bit targets
+    /// instead of a single pointer. Avoid holding many results at once on small stacks.
+    pub const INLINE_CAPACITY: usize = 128;
+
     /// Creates a new error and captures the backtrace (if enabled).
+    ///
+    /// The error's [category](Self::category) is set to `"other"`.
+    #[cfg(feature = "alloc")]
+    #[track_caller]
     pub fn new(message: String) -> Self {
-        Self(Box::new(ErrorInner {
+        Self::tagged("other", message)
+    }
+
+    /// Creates a new error with the specified [category](Self::category)
+    /// and captures the backtrace (if enabled).
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    pub fn tagged(category: &'static str, message: String) -> Self {
+        Self::from_message(category, message)
+    }
+
+    /// Returns a builder that creates an error with optional properties set in one expression.
+    /// ```
+    /// let err = cadd::Error::builder("quota exceeded".into())
+    ///     .category("quota")
+    ///     .retryable(true)
+    ///     .field("user_id", 42)
+    ///     .build();
+    /// assert_eq!(err.message(), "quota exceeded");
+    /// assert_eq!(err.category(), "quota");
+    /// assert!(err.is_retryable());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn builder(message: String) -> ErrorBuilder {
+        ErrorBuilder {
+            message,
+            category: "other",
+            location: None,
+            retryable: false,
+            #[cfg(feature = "source-value")]
+            source_value: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Creates a new error with a message that is formatted only when it's needed,
+    /// and captures the backtrace (if enabled).
+    ///
+    /// The closure is called every time the error is displayed. Compared to [`Error::new`], this avoids
+    /// formatting the message upfront, which is useful if most errors are handled without being displayed,
+    /// at the cost of boxing the closure and a dynamic call at format time. [`message`](Self::message)
+    /// calls the closure once and caches the result.
+    ///
+    /// The error's [category](Self::category) is set to `"other"`.
+    /// ```
+    /// let limit = 10;
+    /// let err = cadd::Error::from_fn(move |f| write!(f, "limit of {limit} exceeded"));
+    /// assert_eq!(err.message(), "limit of 10 exceeded");
+    /// ```
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn from_fn(f: impl Fn(&mut Formatter<'_>) -> fmt::Result + Send + Sync + 'static) -> Self {
+        let mut err = Self::from_message("other", String::new());
+        err.0.lazy_message = Some(LazyMessage {
+            format: Box::new(f),
+            cache: OnceLock::new(),
+        });
+        err
+    }
+
+    // Errors are created by many monomorphized implementations, so keep the formatting code
+    // in this single out-of-line function.
+    #[doc(hidden)]
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    pub fn from_args(category: &'static str, args: fmt::Arguments<'_>) -> Self {
+        #[cfg(feature = "alloc")]
+        let message = alloc::fmt::format(args);
+        #[cfg(not(feature = "alloc"))]
+        let message = {
+            let mut message = InlineString::new();
+            // `InlineString` truncates instead of failing.
+            let _ = fmt::Write::write_fmt(&mut message, args);
+            message.mark_truncated();
+            message
+        };
+        Self::from_message(category, message)
+    }
+
+    #[track_caller]
+    fn from_message(category: &'static str, message: Message) -> Self {
+        #[cfg(feature = "alloc")]
+        let message = truncate_message(message);
+        let inner = ErrorInner {
             message,
+            category,
+            location: Location::caller(),
+            retryable: false,
+            #[cfg(feature = "std")]
+            lazy_message: None,
+            #[cfg(feature = "source-value")]
+            source_value: None,
+            #[cfg(feature = "alloc")]
+            fields: Vec::new(),
             #[cfg(feature = "std")]
             backtrace: Backtrace::capture(),
-        }))
+        };
+        #[cfg(feature = "alloc")]
+        return Self(Box::new(inner));
+        #[cfg(not(feature = "alloc"))]
+        return Self(inner);
     }
 
     /// Description of the error.
     pub fn message(&self) -> &str {
+        #[cfg(feature = "std")]
+        if let Some(lazy) = &self.0.lazy_message {
+            return lazy.cache.get_or_init(|| alloc::format!("{lazy}"));
+        }
         &self.0.message
     }
 
+    /// Returns `true` if the [message](Self::message) is equal to `expected`.
+    ///
+    /// Unlike comparing the output of `to_string()`, this ignores the backtrace, so it's suitable for tests.
+    /// See also [`assert_cadd_err!`](crate::assert_cadd_err).
+    /// ```
+    /// use cadd::ops::Cadd;
+    /// assert!(200u8.cadd(100u8).unwrap_err().matches_message("overflow: 200 + 100"));
+    /// ```
+    pub fn matches_message(&self, expected: &str) -> bool {
+        self.message() == expected
+    }
+
+    /// A short stable tag describing the failed operation, e.g. `"add"`, `"mul"` or `"convert"`.
+    ///
+    /// Unlike the message, it doesn't contain any values, so it can be used as a low-cardinality
+    /// label for metrics.
+    /// ```
+    /// use cadd::ops::Cmul;
+    /// assert_eq!(200u8.cmul(2).unwrap_err().category(), "mul");
+    /// ```
+    pub fn category(&self) -> &'static str {
+        self.0.category
+    }
+
+    /// Location in the source code where the error was created.
+    ///
+    /// Unlike the [backtrace](Self::backtrace), it's always available and cheap to capture.
+    /// Checked operations and conversions report the location of their caller.
+    /// ```
+    /// use cadd::ops::Cadd;
+    /// let err = 200u8.cadd(100u8).unwrap_err();
+    /// assert_eq!(err.location().line(), line!() - 1);
+    /// ```
+    pub fn location(&self) -> &'static Location<'static> {
+        self.0.location
+    }
+
+    /// Whether the failed operation may succeed if it's retried later.
+    ///
+    /// Errors created by this library are never retryable because overflows and invalid values
+    /// don't fix themselves. Use [`retryable`](Self::retryable) to mark your own errors as transient.
+    /// ```
+    /// use cadd::ops::Cadd;
+    /// assert!(!200u8.cadd(100u8).unwrap_err().is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        self.0.retryable
+    }
+
+    /// Sets the value returned by [`is_retryable`](Self::is_retryable).
+    /// ```
+    /// use cadd::ops::Cadd;
+    /// let err = 200u8.cadd(100u8).unwrap_err().retryable(true);
+    /// assert!(err.is_retryable());
+    /// ```
+    #[must_use]
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.0.retryable = retryable;
+        self
+    }
+
+    /// Attaches the value that failed to convert, so that it can be recovered
+    /// with [`take_source_value`](Self::take_source_value).
+    #[cfg(feature = "source-value")]
+    #[must_use]
+    pub fn with_source_value<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.0.source_value = Some(Box::new(value));
+        self
+    }
+
+    /// Removes and returns the value that failed to convert, if it's present and has type `T`.
+    ///
+    /// Conversions that consume an owned value (e.g. `String` from `Vec<u8>` or `CString`)
+    /// attach it to the error, so it can be reused without cloning it before the conversion.
+    /// ```
+    /// use cadd::convert::Cinto;
+    ///
+    /// let mut err = Cinto::<String>::cinto(vec![0xff, b'a']).unwrap_err();
+    /// assert_eq!(err.take_source_value::<Vec<u8>>(), Some(vec![0xff, b'a']));
+    /// assert_eq!(err.take_source_value::<Vec<u8>>(), None);
+    /// ```
+    #[cfg(feature = "source-value")]
+    pub fn take_source_value<T: Any>(&mut self) -> Option<T> {
+        match self.0.source_value.take()?.downcast::<T>() {
+            Ok(value) => Some(*value),
+            Err(value) => {
+                self.0.source_value = Some(value);
+                None
+            }
+        }
+    }
+
+    /// Attaches a key/value pair describing the context of the error, e.g. an ID of the processed entity.
+    ///
+    /// Fields are included in the `Debug` output and in the [JSON](Self::json) output,
+    /// but not in the `Display` output or in the [message](Self::message).
+    /// ```
+    /// use cadd::ops::Cadd;
+    ///
+    /// let err = 200u8.cadd(100u8).unwrap_err().with_field("user_id", 42);
+    /// assert_eq!(err.fields(), [("user_id", "42".to_string())]);
+    /// assert!(format!("{err:?}").starts_with("overflow: 200 + 100\nfields: user_id=42"));
+    /// assert!(err.json().to_string().ends_with(r#""fields":{"user_id":"42"}}"#));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn with_field(mut self, key: &'static str, value: impl Display) -> Self {
+        self.0.fields.push((key, alloc::format!("{value}")));
+        self
+    }
+
+    /// Key/value pairs attached with [`with_field`](Self::with_field), in the order they were added.
+    #[cfg(feature = "alloc")]
+    pub fn fields(&self) -> &[(&'static str, String)] {
+        &self.0.fields
+    }
+
+    /// Returns a wrapper that displays the error as a single-line JSON object
+    /// for structured logging.
+    ///
+    /// The object contains the [message](Self::message), the [category](Self::category)
+    /// and the [location](Self::location) split into `location` (file), `line` and `column` fields.
+    /// The backtrace is not included.
+    /// ```
+    /// use cadd::ops::Cadd;
+    ///
+    /// let err = 200u8.cadd(100u8).unwrap_err();
+    /// let json = err.json().to_string();
+    /// assert!(json.starts_with(r#"{"message":"overflow: 200 + 100","category":"add","location":""#));
+    /// ```
+    pub fn json(&self) -> ErrorJson<'_> {
+        ErrorJson(self)
+    }
+
+    /// Writes the message of the error to `w` without allocating.
+    ///
+    /// Unlike formatting the error with `{}` or `{:?}`, the backtrace is not included.
+    /// Use [`write_backtrace`](Self::write_backtrace) to write it separately.
+    /// ```
+    /// use {cadd::ops::Cadd, core::fmt::Write};
+    ///
+    /// let mut buf = String::new();
+    /// 200u8.cadd(100u8).unwrap_err().write_message(&mut buf).unwrap();
+    /// assert_eq!(buf, "overflow: 200 + 100");
+    /// ```
+    pub fn write_message(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{}", MessageDisplay(self))
+    }
+
+    /// Writes the backtrace of the error to `w`. Writes nothing if the backtrace wasn't captured.
+    #[cfg(feature = "std")]
+    pub fn write_backtrace(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        if self.0.backtrace.status() == BacktraceStatus::Captured {
+            write!(w, "{}", self.0.backtrace)?;
+        }
+        Ok(())
+    }
+
     /// Backtrace to where the error was created.
     #[cfg(feature = "std")]
     pub fn backtrace(&self) -> &Backtrace {
         &self.0.backtrace
     }
+
+    /// Formatted backtrace to where the error was created, or `None` if the backtrace wasn't captured.
+    ///
+    /// This is useful for logging the backtrace separately from the [message](Self::message).
+    #[cfg(feature = "std")]
+    pub fn backtrace_string(&self) -> Option<String> {
+        if self.0.backtrace.status() == BacktraceStatus::Captured {
+            Some(alloc::format!("{}", self.0.backtrace))
+        } else {
+            None
+        }
+    }
 }
 
-impl Debug for Error {
+/// Asserts that a `cadd::Result` is an error with the specified message.
+///
+/// Only the [message](Error::message) is compared, so the assertion doesn't depend on whether
+/// backtraces are enabled. Panics with the value if the result is `Ok`.
+/// ```
+/// use cadd::{assert_cadd_err, ops::Cadd};
+///
+/// assert_cadd_err!(200u8.cadd(100u8), "overflow: 200 + 100");
+/// ```
+/// ```should_panic
+/// use cadd::{assert_cadd_err, ops::Cadd};
+///
+/// assert_cadd_err!(2u8.cadd(3u8), "overflow: 2 + 3");
+/// ```
+#[macro_export]
+macro_rules! assert_cadd_err {
+    ($result:expr, $expected:expr $(,)?) => {
+        match $result {
+            ::core::result::Result::Ok(value) => {
+                ::core::panic!("expected error {:?}, got Ok({:?})", $expected, value)
+            }
+            ::core::result::Result::Err(err) => {
+                let err: $crate::Error = err;
+                ::core::assert_eq!(err.message(), $expected);
+            }
+        }
+    };
+}
+
+/// Creates an [`Error`] with the specified category and a `format!`-style message.
+macro_rules! error {
+    ($category:expr, $($arg:tt)*) => {
+        $crate::Error::from_args($category, ::core::format_args!($($arg)*))
+    };
+}
+pub(crate) use error;
+
+/// Builder for [`Error`] returned by [`Error::builder`].
+///
+/// All properties are optional. [`Error::new`] is a shortcut for building an error without them.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub struct ErrorBuilder {
+    message: String,
+    category: &'static str,
+    location: Option<&'static Location<'static>>,
+    retryable: bool,
+    #[cfg(feature = "source-value")]
+    source_value: Option<Box<dyn Any + Send + Sync>>,
+    fields: Vec<(&'static str, String)>,
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorBuilder {
+    /// Sets the [category](Error::category). The default is `"other"`.
+    pub fn category(mut self, category: &'static str) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Sets the [location](Error::location). By default, it's the location of the
+    /// [`build`](Self::build) call.
+    pub fn location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the value returned by [`is_retryable`](Error::is_retryable). The default is `false`.
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Attaches the value that failed to convert. See [`Error::with_source_value`].
+    #[cfg(feature = "source-value")]
+    pub fn source_value<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.source_value = Some(Box::new(value));
+        self
+    }
+
+    /// Attaches a key/value pair. See [`Error::with_field`].
+    pub fn field(mut self, key: &'static str, value: impl Display) -> Self {
+        self.fields.push((key, alloc::format!("{value}")));
+        self
+    }
+
+    /// Creates the error and captures the backtrace (if enabled).
+    #[track_caller]
+    pub fn build(self) -> Error {
+        let mut err = Error::from_message(self.category, self.message);
+        if let Some(location) = self.location {
+            err.0.location = location;
+        }
+        err.0.retryable = self.retryable;
+        #[cfg(feature = "source-value")]
+        {
+            err.0.source_value = self.source_value;
+        }
+        err.0.fields = self.fields;
+        err
+    }
+}
+
+#[cfg(feature = "alloc")]
+static MAX_MESSAGE_LEN: AtomicUsize = AtomicUsize::new(4096);
+
+/// Sets the maximum length of error messages in bytes. The default is 4096.
+///
+/// Longer messages are truncated when the error is created, and a `…[truncated N bytes]` suffix
+/// is appended. This prevents huge messages when an error includes a large value, e.g. a long byte buffer.
+/// The limit doesn't apply to messages of [`Error::from_fn`].
+///
+/// Without the `alloc` feature, messages are always limited to [`Error::INLINE_CAPACITY`] bytes.
+/// ```
+/// cadd::set_max_message_len(8);
+/// let err = cadd::Error::new("a long error message".into());
+/// assert_eq!(err.message(), "a long e…[truncated 12 bytes]");
+/// # cadd::set_max_message_len(4096);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn set_max_message_len(len: usize) {
+    MAX_MESSAGE_LEN.store(len, Ordering::Relaxed);
+}
+
+#[cfg(feature = "alloc")]
+fn truncate_message(mut message: String) -> String {
+    let max_len = MAX_MESSAGE_LEN.load(Ordering::Relaxed);
+    if message.len() > max_len {
+        let mut len = max_len;
+        while !message.is_char_boundary(len) {
+            len -= 1;
+        }
+        let truncated = message.len() - len;
+        message.truncate(len);
+        let _ = fmt::Write::write_fmt(&mut message, format_args!("…[truncated {truncated} bytes]"));
+    }
+    message
+}
+
+/// Function that writes a replacement for the [message](Error::message) of an error,
+/// e.g. a translation to another language. See [`set_message_formatter`].
+#[cfg(feature = "std")]
+pub type MessageFormatter = fn(&Error, &mut Formatter<'_>) -> fmt::Result;
+
+#[cfg(feature = "std")]
+static HAS_FORMATTERS: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "std")]
+static FORMATTERS: RwLock<Vec<(&'static str, MessageFormatter)>> = RwLock::new(Vec::new());
+
+/// Overrides how messages of errors with the specified [category](Error::category) are displayed.
+///
+/// The formatter is used by the `Display` and `Debug` implementations of [`Error`].
+/// [`Error::message`] always returns the original English message, so the formatter can
+/// fall back to it. Setting a formatter for the same category again replaces the previous one.
+///
+/// If no formatter is set, displaying an error only costs one atomic load.
+/// ```
+/// use {cadd::ops::Cadd, std::fmt};
+///
+/// fn overflow_de(err: &cadd::Error, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///     write!(f, "Überlauf ({})", err.message())
+/// }
+///
+/// cadd::set_message_formatter("add", overflow_de);
+/// let err = 200u8.cadd(100u8).unwrap_err();
+/// assert!(err.to_string().starts_with("Überlauf (overflow: 200 + 100)"));
+/// ```
+#[cfg(feature = "std")]
+pub fn set_message_formatter(category: &'static str, formatter: MessageFormatter) {
+    let mut formatters = FORMATTERS.write().unwrap_or_else(PoisonError::into_inner);
+    match formatters.iter_mut().find(|(c, _)| *c == category) {
+        Some(entry) => entry.1 = formatter,
+        None => formatters.push((category, formatter)),
+    }
+    HAS_FORMATTERS.store(true, Ordering::Release);
+}
+
+impl Error {
+    fn fmt_message(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "std")]
+        if HAS_FORMATTERS.load(Ordering::Acquire) {
+            // Release the lock before calling the formatter so that it can set formatters itself.
+            let formatter = FORMATTERS
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .find(|(c, _)| *c == self.category())
+                .map(|(_, formatter)| *formatter);
+            if let Some(formatter) = formatter {
+                return formatter(self, f);
+            }
+        }
+        #[cfg(feature = "std")]
+        if let Some(lazy) = &self.0.lazy_message {
+            return match lazy.cache.get() {
+                Some(message) => f.write_str(message),
+                None => Display::fmt(lazy, f),
+            };
+        }
+        write!(f, "{}", self.message())
+    }
+}
+
+/// Displays the message of an [`Error`]. Used by [`Error::write_message`].
+struct MessageDisplay<'a>(&'a Error);
+
+impl Display for MessageDisplay<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0.message)?;
+        self.0.fmt_message(f)
+    }
+}
+
+impl Error {
+    fn fmt_backtrace(&self, f: &mut Formatter<'_>) -> fmt::Result {
         #[cfg(feature = "std")]
         if self.0.backtrace.status() == BacktraceStatus::Captured {
             write!(f, "\nstack backtrace:\n{}", self.0.backtrace)?;
         }
+        let _ = f;
         Ok(())
     }
 }
 
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_message(f)?;
+        #[cfg(feature = "alloc")]
+        for (i, (key, value)) in self.0.fields.iter().enumerate() {
+            let prefix = if i == 0 { "\nfields: " } else { ", " };
+            write!(f, "{prefix}{key}={value}")?;
+        }
+        self.fmt_backtrace(f)
+    }
+}
+
+/// Same as `Debug`, but without the [fields](Error::fields).
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        self.fmt_message(f)?;
+        self.fmt_backtrace(f)
     }
 }
 
 impl core::error::Error for Error {}
+
+/// Displays an [`Error`] as a single-line JSON object. Returned by [`Error::json`].
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorJson<'a>(&'a Error);
+
+impl Display for ErrorJson<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let location = self.0.location();
+        write!(f, "{{\"message\":")?;
+        write_json_string(f, self.0.message())?;
+        write!(f, ",\"category\":")?;
+        write_json_string(f, self.0.category())?;
+        write!(f, ",\"location\":")?;
+        write_json_string(f, location.file())?;
+        write!(
+            f,
+            ",\"line\":{},\"column\":{}",
+            location.line(),
+            location.column()
+        )?;
+        #[cfg(feature = "alloc")]
+        if !self.0.fields().is_empty() {
+            f.write_str(",\"fields\":{")?;
+            for (i, (key, value)) in self.0.fields().iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                write_json_string(f, key)?;
+                f.write_str(":")?;
+                write_json_string(f, value)?;
+            }
+            f.write_str("}")?;
+        }
+        f.write_str("}")
+    }
+}
+
+fn write_json_string(f: &mut Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => fmt::Write::write_char(f, c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Fixed-capacity string that truncates its content on a char boundary.
+#[cfg(not(feature = "alloc"))]
+struct InlineString {
+    buf: [u8; Error::INLINE_CAPACITY],
+    len: usize,
+    truncated: bool,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl InlineString {
+    fn new() -> Self {
+        Self {
+            buf: [0; Error::INLINE_CAPACITY],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Replaces the end of a truncated string with `…`.
+    fn mark_truncated(&mut self) {
+        const MARKER: &str = "…";
+        if !self.truncated {
+            return;
+        }
+        let mut len = self.len.min(self.buf.len() - MARKER.len());
+        // Continuation bytes of UTF-8 sequences have the form `0b10xxxxxx`.
+        while self.buf[len] & 0b1100_0000 == 0b1000_0000 {
+            len -= 1;
+        }
+        self.buf[len..len + MARKER.len()].copy_from_slice(MARKER.as_bytes());
+        self.len = len + MARKER.len();
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl core::ops::Deref for InlineString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        // Only whole UTF-8 sequences are ever written to `buf`.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Write for InlineString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+        let available = self.buf.len() - self.len;
+        let mut count = s.len().min(available);
+        while !s.is_char_boundary(count) {
+            count -= 1;
+        }
+        self.buf[self.len..self.len + count].copy_from_slice(&s.as_bytes()[..count]);
+        self.len += count;
+        self.truncated = count < s.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+//! Exports the library's free functions without any traits.
+//!
+//! Use this module if you prefer importing functions by name instead of a wildcard import of the [prelude](crate::prelude):
+//! ```
+//! use cadd::funcs::{cadd, cmul};
+//!
+//! fn f(a: u32, b: u32) -> cadd::Result<u32> {
+//!     cadd(cmul(a, b)?, 1)
+//! }
+//! ```
+//! Each function is a shorthand for the method of the corresponding trait, which can be imported from
+//! [`traits`](crate::traits).
+
+pub use crate::{
+    convert::non_zero,
+    ops::{
+        apply_basis_points, cabs, cadd, cadd_const_u32, cadd_const_u64, ccmp, cdiv, cdiv_euclid,
+        cdiv_exact, cdiv_pow2, cdiv_round, cicbrt, cilog, cilog10, cilog2, cisqrt, cmul,
+        cmul_const_u32, cmul_const_u64, cneg, cnext_multiple_of, cnext_power_of_two, cpow,
+        cpow_widening, crem, crem_euclid, cshl, cshl_lossless, cshr, cshr_lossless, csub, distance,
+        pack_bits, percent_of, unpack_bits,
+    },
+};
@@ -0,0 +1,32 @@
+//! Checked operations on hash maps.
+//!
+//! Requires the `std` feature.
+
+use {crate::error::error, core::hash::Hash, std::collections::HashMap};
+
+/// Creates an empty `HashMap` with space for at least `capacity` entries.
+///
+/// Unlike [`HashMap::with_capacity`], returns an error instead of panicking or aborting
+/// if the capacity overflows or if the allocation fails. Use it if `capacity` comes from untrusted input.
+/// ```
+/// use {cadd::hash_map::cwith_capacity, std::collections::HashMap};
+///
+/// let map: HashMap<u32, u64> = cwith_capacity(100).unwrap();
+/// assert!(map.capacity() >= 100);
+/// assert!(cwith_capacity::<u32, u64>(usize::MAX).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn cwith_capacity<K: Eq + Hash, V>(capacity: usize) -> crate::Result<HashMap<K, V>> {
+    let mut map = HashMap::new();
+    if let Err(err) = map.try_reserve(capacity) {
+        return Err(error!(
+            "with_capacity",
+            "cannot allocate {} entries of {} bytes: {}",
+            capacity,
+            size_of::<(K, V)>(),
+            err,
+        ));
+    }
+    Ok(map)
+}
@@ -0,0 +1,51 @@
+//! Checked allocation size calculations.
+
+use {crate::error::error, core::alloc::Layout};
+
+/// Size of an array of `count` elements of `elem_size` bytes each: `count * elem_size`.
+/// Returns an error on overflow.
+/// ```
+/// use cadd::layout::layout_size;
+///
+/// assert_eq!(layout_size(10, 8).unwrap(), 80);
+/// assert_eq!(
+///     layout_size(usize::MAX, 2).unwrap_err().message(),
+///     format!("allocation size overflow: {} elements of 2 bytes", usize::MAX),
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn layout_size(count: usize, elem_size: usize) -> crate::Result<usize> {
+    // `checked_mul` instead of `cmul` to avoid creating an error that would be replaced with this one.
+    match count.checked_mul(elem_size) {
+        Some(size) => Ok(size),
+        None => Err(error!(
+            "layout",
+            "allocation size overflow: {} elements of {} bytes", count, elem_size,
+        )),
+    }
+}
+
+/// Layout of an array of `count` elements of `elem_size` bytes each, aligned to `align`.
+///
+/// Returns an error if the size overflows, if `align` is not a power of two, or if the size
+/// rounded up to `align` exceeds `isize::MAX`.
+/// ```
+/// use cadd::layout::array_layout;
+///
+/// let layout = array_layout(10, 8, 8).unwrap();
+/// assert_eq!((layout.size(), layout.align()), (80, 8));
+/// assert!(array_layout(10, 8, 3).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn array_layout(count: usize, elem_size: usize, align: usize) -> crate::Result<Layout> {
+    let size = layout_size(count, elem_size)?;
+    match Layout::from_size_align(size, align) {
+        Ok(layout) => Ok(layout),
+        Err(_) => Err(error!(
+            "layout",
+            "invalid layout: {} elements of {} bytes with alignment {}", count, elem_size, align,
+        )),
+    }
+}
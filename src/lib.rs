@@ -1,5 +1,7 @@
 #![no_std]
 #![warn(missing_docs)]
+// Without `alloc`, `Error` stores its message inline, so it's large by design (see `Error::INLINE_CAPACITY`).
+#![cfg_attr(not(feature = "alloc"), allow(clippy::result_large_err))]
 //! # `cadd`: painless checked arithmetics and conversions
 //!
 //! Features:
@@ -103,6 +105,19 @@
 //! function in `cadd`: [`cdiv_euclid`](https://docs.rs/cadd/latest/cadd/ops/fn.cdiv_euclid.html),
 //! [`cilog2`](https://docs.rs/cadd/latest/cadd/ops/fn.cilog2.html), and so on.
 //! See [`ops`](https://docs.rs/cadd/latest/cadd/ops/index.html) module documentation for more information.
+//!
+//! ## Crate features
+//!
+//! * `std` (enabled by default): captures backtraces in errors and enables conversions of `std` types.
+//!   Implies `alloc`.
+//! * `alloc` (enabled by default): enables conversions of `alloc` types. Without this feature,
+//!   error messages are stored in a fixed-capacity inline buffer and are truncated (ending with `…`)
+//!   if they are too long. This makes `Error` about 176 bytes large.
+//! * `duration-parse`: enables parsing `Duration` from strings like `"1h30m"` with `Cfrom<&str>`.
+//! * `source-value`: owned values that failed to convert are stored in the error and can be recovered
+//!   with `Error::take_source_value`. Implies `alloc`.
+//! * `num-rational`: enables conversion from `f64` to [`Ratio<i64>`](https://docs.rs/num-rational/latest/num_rational/struct.Ratio.html).
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 #[cfg(any(test, feature = "std"))]
 extern crate std;
@@ -110,14 +125,35 @@ extern crate std;
 mod convert_impls;
 mod error;
 mod ops_impls;
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
+#[cfg(all(test, not(feature = "alloc")))]
+mod tests_no_alloc;
 
+pub mod accumulate;
+pub mod bytes;
 pub mod convert;
+pub mod funcs;
+#[cfg(feature = "std")]
+pub mod hash_map;
+pub mod layout;
+pub mod net;
 pub mod ops;
+pub mod parse;
 pub mod prelude;
+pub mod range;
+#[cfg(feature = "alloc")]
+pub mod string;
+pub mod time;
+pub mod traits;
+#[cfg(feature = "alloc")]
+pub mod vec;
 
-pub use crate::error::Error;
+#[cfg(feature = "alloc")]
+pub use crate::error::{set_max_message_len, ErrorBuilder};
+#[cfg(feature = "std")]
+pub use crate::error::{set_message_formatter, MessageFormatter};
+pub use crate::error::{Error, ErrorJson};
 
 /// `Result` with error type defaulting to `cadd::Error`.
 pub type Result<T, E = Error> = core::result::Result<T, E>;
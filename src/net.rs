@@ -0,0 +1,50 @@
+//! Checked operations on network addresses.
+
+use {crate::error::error, core::net::SocketAddr};
+
+/// Returns the address with the port incremented by one.
+///
+/// Returns an error if the port is already 65535.
+/// ```
+/// use {cadd::net::next_port, std::net::SocketAddr};
+///
+/// let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+/// assert_eq!(next_port(addr).unwrap().port(), 8081);
+/// ```
+#[inline]
+#[track_caller]
+pub fn next_port(addr: SocketAddr) -> crate::Result<SocketAddr> {
+    with_port_offset(addr, 1)
+}
+
+/// Returns the address with `offset` added to the port.
+///
+/// Returns an error if the resulting port is outside of the range from 0 to 65535.
+/// ```
+/// use {cadd::net::with_port_offset, std::net::SocketAddr};
+///
+/// let addr: SocketAddr = "[::1]:8080".parse().unwrap();
+/// assert_eq!(with_port_offset(addr, -80).unwrap().port(), 8000);
+/// assert_eq!(
+///     with_port_offset(addr, 60000).unwrap_err().message(),
+///     "port is out of range: 8080 + 60000 for [::1]:8080",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn with_port_offset(mut addr: SocketAddr, offset: i32) -> crate::Result<SocketAddr> {
+    let port = i32::from(addr.port())
+        .checked_add(offset)
+        .and_then(|port| u16::try_from(port).ok());
+    let Some(port) = port else {
+        return Err(error!(
+            "port",
+            "port is out of range: {} + {} for {}",
+            addr.port(),
+            offset,
+            addr,
+        ));
+    };
+    addr.set_port(port);
+    Ok(addr)
+}
@@ -45,6 +45,7 @@
 //!       cmul(mass, velocity.cpow(2)?)?.cdiv(2)
 //!   }
 //!   ```
+//!   Code that returns `Option` can use the [`CaddOption`] methods (`cadd_opt`, `cmul_opt`, etc) instead.
 //! * The error values they return provide a meaningful error message and a backtrace:
 //!   ```
 //!   # use cadd::ops::{Cpow, Cdiv, cmul};
@@ -106,6 +107,7 @@ macro_rules! declare_binary_trait {
 
         #[doc = $doc]
         #[inline]
+        #[track_caller]
         pub fn $trait_fn<T1, T2>(a: T1, b: T2) -> Result<T1::Output, T1::Error>
         where
             T1: $trait_<T2>,
@@ -127,6 +129,7 @@ macro_rules! declare_unary_trait {
 
         #[doc = $doc]
         #[inline]
+        #[track_caller]
         pub fn $trait_fn<T1>(value: T1) -> Result<T1::Output, T1::Error>
         where
             T1: $trait_,
@@ -139,7 +142,30 @@ macro_rules! declare_unary_trait {
 declare_binary_trait!(
     Cadd,
     cadd,
-    "Addition: `a + b`. Returns an error on overflow."
+    "Addition: `a + b`. Returns an error on overflow.
+
+For unsigned [`NonZero`](core::num::NonZero) types, the other operand can be the corresponding plain integer
+on either side, and the result is `NonZero`. [`Cmul`] also accepts mixed operands, but its result
+is a plain integer because it can be zero.
+```
+use {cadd::ops::{Cadd, Cmul}, std::num::NonZero};
+
+let one = NonZero::new(1u8).unwrap();
+assert_eq!(one.cadd(2u8).unwrap().get(), 3);
+assert_eq!(2u8.cadd(one).unwrap().get(), 3);
+assert_eq!(0u8.cmul(one).unwrap(), 0);
+assert_eq!(one.cmul(5u8).unwrap(), 5);
+```
+
+For integer primitives, this and other arithmetic traits are also implemented for references,
+so values from a slice can be used without copying them explicitly:
+```
+use cadd::ops::cadd;
+
+let values = [1u32, 2, 3];
+let sum = values.iter().try_fold(0, |acc, x| cadd(acc, x)).unwrap();
+assert_eq!(sum, 6);
+```"
 );
 declare_binary_trait!(
     Csub,
@@ -155,7 +181,42 @@ declare_binary_trait!(
 declare_binary_trait!(
     Cdiv,
     cdiv,
-    "Division: `a / b`. Returns an error on overflow or if the divisor is zero."
+    "Division: `a / b`. Returns an error on overflow or if the divisor is zero.
+
+If the divisor is a known power of two, [`CdivPow2`] guarantees that a shift is used instead of a division.
+If the division is expected to be exact, use [`CdivExact`]."
+);
+declare_binary_trait!(
+    CdivPow2,
+    cdiv_pow2,
+    "Division by a power of two: <code>a / 2<sup>b</sup></code>, implemented as a shift.
+Returns an error if `b` is greater or equal to the number of bits in the type.
+
+Unlike `a >> b`, the result is rounded toward zero for negative numbers, same as with [`Cdiv`].
+```
+use cadd::ops::CdivPow2;
+
+assert_eq!(20u32.cdiv_pow2(2).unwrap(), 5);
+assert_eq!((-7i32).cdiv_pow2(1).unwrap(), -3);
+assert!(1u8.cdiv_pow2(8).is_err());
+```"
+);
+declare_binary_trait!(
+    CdivExact,
+    cdiv_exact,
+    "Exact division: `a / b`. Returns an error if the remainder is not zero,
+on overflow or if the divisor is zero.
+
+Use it instead of [`Cdiv`] if a truncated result would indicate a bug, e.g. in unit conversions.
+```
+use cadd::ops::CdivExact;
+
+assert_eq!(12u32.cdiv_exact(4).unwrap(), 3);
+assert_eq!(
+    13u32.cdiv_exact(4).unwrap_err().message(),
+    \"division is not exact: 13 / 4 (remainder 1)\",
+);
+```"
 );
 declare_binary_trait!(
     CdivEuclid,
@@ -176,7 +237,10 @@ declare_binary_trait!(
 declare_binary_trait!(
     CILog,
     cilog,
-    "Logarithm: <code>log<sub>b</sub> a</code>. Returns an error if the number is negative or zero, or if the base is less than 2."
+    "Logarithm: <code>log<sub>b</sub> a</code>. Returns an error if the number is negative or zero, or if the base is less than 2.
+
+The base can also be a [`NonZero`](core::num::NonZero) value of the same type.
+Note that it only rules out zero, so a base of 1 (or a negative base) is still an error."
 );
 declare_unary_trait!(
     CILog2,
@@ -191,17 +255,58 @@ declare_unary_trait!(
 declare_binary_trait!(
     Cshl,
     cshl,
-    "Shift left: `a << b`. Returns an error if `b` is greater or equal to the number of bits in the type."
+    "Shift left: `a << b`. Returns an error if `b` is greater or equal to the number of bits in the type.
+
+It's also implemented for unsigned [`NonZero`](core::num::NonZero) types. In this case, it additionally
+returns an error if all set bits are shifted out, because the result would be zero.
+```
+use {cadd::ops::Cshl, std::num::NonZero};
+
+let value = NonZero::new(0b0110u8).unwrap();
+assert_eq!(value.cshl(6).unwrap().get(), 0b1000_0000);
+assert!(value.cshl(7).is_err());
+```"
 );
 declare_binary_trait!(
     Cshr,
     cshr,
     "Shift right: `a >> b`. Returns an error if `b` is greater or equal to the number of bits in the type."
 );
+declare_binary_trait!(
+    CshlLossless,
+    cshl_lossless,
+    "Lossless shift left: `a << b`. Returns an error if `b` is greater or equal to the number of bits in the type,
+or if any bits would be lost, i.e. if the result is not equal to <code>a * 2<sup>b</sup></code>.
+For signed types, this also means that the sign of the value can't change."
+);
+declare_binary_trait!(
+    CshrLossless,
+    cshr_lossless,
+    "Lossless shift right: `a >> b`. Returns an error if `b` is greater or equal to the number of bits in the type,
+or if any non-zero bits would be shifted off, i.e. if `a` is not divisible by <code>2<sup>b</sup></code>."
+);
 declare_binary_trait!(
     Cpow,
     cpow,
-    "Exponentiation: <code>a<sup>b</sup></code>. Returns an error on overflow."
+    "Exponentiation: <code>a<sup>b</sup></code>. Returns an error on overflow.
+
+For floating point numbers (`f32` and `f64`), returns an error if the result is infinite or NaN.
+Float implementations require the `std` feature."
+);
+declare_binary_trait!(
+    CpowWidening,
+    cpow_widening,
+    "Exponentiation in a wider type: <code>a<sup>b</sup></code> computed in the integer type twice as wide as `a`
+(e.g. `u64` for `u32`). Returns an error if the result overflows even the wider type.
+
+It's implemented for integers up to 64 bits. There is no native type wider than `u128` and `i128`,
+so use [`Cpow`] for them.
+```
+use cadd::ops::CpowWidening;
+
+assert_eq!(10u32.cpow_widening(12).unwrap(), 1_000_000_000_000u64);
+assert!(10u32.cpow_widening(20).is_err());
+```"
 );
 declare_unary_trait!(
     Cabs,
@@ -213,6 +318,20 @@ declare_unary_trait!(
     cisqrt,
     "Square root: `√a` (signed types only). Returns an error if `a` is negative."
 );
+declare_unary_trait!(
+    Cicbrt,
+    cicbrt,
+    "Integer cube root: <code>∛a</code> rounded down (unsigned types only).
+
+It never fails for unsigned types, but returns `Result` for consistency with other operations.
+```
+use cadd::ops::Cicbrt;
+
+assert_eq!(27u32.cicbrt().unwrap(), 3);
+assert_eq!(63u32.cicbrt().unwrap(), 3);
+assert_eq!(u64::MAX.cicbrt().unwrap(), 2_642_245);
+```"
+);
 declare_binary_trait!(
     CnextMultipleOf,
     cnext_multiple_of,
@@ -223,3 +342,487 @@ declare_unary_trait!(
     cnext_power_of_two,
     "Next power of 2. Returns an error on overflow."
 );
+declare_binary_trait!(
+    Distance,
+    distance,
+    "Absolute difference between two time points: `|a - b|`, regardless of their order. Returns an error if the difference can't be represented."
+);
+
+/// Rounding mode for operations that can't return an exact result, such as [`cdiv_round`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards zero (truncate). This is how `a / b` works for integers.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest value. Ties are rounded away from zero.
+    HalfAwayFromZero,
+    /// Round to the nearest value. Ties are rounded to the nearest even value.
+    HalfEven,
+}
+
+/// Division with the specified rounding: `a / b`. Returns an error on overflow or if the divisor is zero.
+#[allow(missing_docs)]
+pub trait CdivRound<Other = Self>: Sized {
+    type Error;
+    type Output;
+    fn cdiv_round(self, b: Other, mode: RoundingMode) -> Result<Self::Output, Self::Error>;
+}
+
+/// Division with the specified rounding: `a / b`. Returns an error on overflow or if the divisor is zero.
+/// ```
+/// use cadd::ops::{cdiv_round, RoundingMode};
+///
+/// assert_eq!(cdiv_round(7, 2, RoundingMode::TowardZero).unwrap(), 3);
+/// assert_eq!(cdiv_round(7, 2, RoundingMode::HalfAwayFromZero).unwrap(), 4);
+/// assert_eq!(cdiv_round(-7, 2, RoundingMode::Floor).unwrap(), -4);
+/// assert_eq!(cdiv_round(-7, 2, RoundingMode::HalfEven).unwrap(), -4);
+/// assert!(cdiv_round(i32::MIN, -1, RoundingMode::Ceil).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn cdiv_round<T1, T2>(a: T1, b: T2, mode: RoundingMode) -> Result<T1::Output, T1::Error>
+where
+    T1: CdivRound<T2>,
+{
+    a.cdiv_round(b, mode)
+}
+
+/// Checks whether an operation would overflow without returning the result.
+///
+/// This is based on the `overflowing_*` family of functions,
+/// such as [`overflowing_add`](u32::overflowing_add).
+/// ```
+/// use cadd::ops::WouldOverflow;
+///
+/// assert!(200u8.would_overflow_mul(2));
+/// assert!(!100u8.would_overflow_mul(2));
+/// assert!(i8::MIN.would_overflow_neg());
+/// ```
+#[allow(missing_docs)]
+pub trait WouldOverflow: Sized {
+    fn would_overflow_add(self, b: Self) -> bool;
+    fn would_overflow_sub(self, b: Self) -> bool;
+    fn would_overflow_mul(self, b: Self) -> bool;
+    fn would_overflow_pow(self, exp: u32) -> bool;
+    fn would_overflow_neg(self) -> bool;
+}
+
+/// Checked arithmetic with an additional upper limit below the type's maximum.
+///
+/// This is useful for quotas and other domain-specific limits. An overflow of the type
+/// results in the same error as [`Cadd`] or [`Cmul`], and a result above `max` results in an error
+/// with the `"limit"` [category](crate::Error::category).
+/// ```
+/// use cadd::ops::Cbounded;
+///
+/// assert_eq!(600u32.cadd_bounded(400, 1000).unwrap(), 1000);
+/// assert_eq!(600u32.cadd_bounded(401, 1000).unwrap_err().message(), "exceeds limit 1000: 1001");
+/// assert!(3u8.cmul_bounded(100, 250).is_err());
+/// ```
+pub trait Cbounded: Sized {
+    /// Adds `b` and returns an error if the sum overflows or exceeds `max`.
+    fn cadd_bounded(self, b: Self, max: Self) -> crate::Result<Self>;
+
+    /// Multiplies by `b` and returns an error if the product overflows or exceeds `max`.
+    fn cmul_bounded(self, b: Self, max: Self) -> crate::Result<Self>;
+}
+
+/// Checked arithmetic returning `Option`, for use with `?` in functions that return `Option`.
+///
+/// The operations fail in the same cases as [`Cadd`], [`Csub`], [`Cmul`] and [`Cdiv`], but no error
+/// is created, so there is no message or backtrace. This also makes them suitable for hot paths
+/// where failures are expected and handled without reporting.
+/// ```
+/// use cadd::ops::{Cadd, CaddOption, Cmul};
+///
+/// fn area_opt(width: u32, height: u32, margin: u32) -> Option<u32> {
+///     width.cadd_opt(margin)?.cmul_opt(height)
+/// }
+///
+/// fn area(width: u32, height: u32, margin: u32) -> cadd::Result<u32> {
+///     width.cadd(margin)?.cmul(height)
+/// }
+///
+/// assert_eq!(area_opt(10, 20, 2), Some(240));
+/// assert_eq!(area_opt(u32::MAX, 20, 2), None);
+/// assert!(area(u32::MAX, 20, 2).is_err());
+/// ```
+pub trait CaddOption: Sized {
+    /// Same as [`Cadd::cadd`], but returns `None` on failure.
+    fn cadd_opt(self, b: Self) -> Option<Self>;
+
+    /// Same as [`Csub::csub`], but returns `None` on failure.
+    fn csub_opt(self, b: Self) -> Option<Self>;
+
+    /// Same as [`Cmul::cmul`], but returns `None` on failure.
+    fn cmul_opt(self, b: Self) -> Option<Self>;
+
+    /// Same as [`Cdiv::cdiv`], but returns `None` on failure.
+    fn cdiv_opt(self, b: Self) -> Option<Self>;
+}
+
+/// Subtraction for unsigned counters that stops at zero instead of failing.
+///
+/// This is the same as [`saturating_sub`](u32::saturating_sub), but it makes the "floor at zero"
+/// intent explicit at the call site. Use [`Csub`] if reaching below zero indicates a bug.
+/// ```
+/// use cadd::ops::CsubOrZero;
+///
+/// assert_eq!(5u32.csub_or_zero(2), 3);
+/// assert_eq!(2u32.csub_or_zero(5), 0);
+/// ```
+pub trait CsubOrZero: Sized {
+    #[allow(missing_docs)]
+    fn csub_or_zero(self, b: Self) -> Self;
+}
+
+/// Absolute value that returns `MAX` instead of failing (signed types only).
+///
+/// This is a total alternative to [`Cabs`]: the only clamped case is `MIN`, which is mapped to `MAX`.
+/// For [`NonZero`](core::num::NonZero) types, the result is still non-zero.
+///
+/// It's the same as the inherent `saturating_abs` methods of signed integers and their `NonZero`
+/// counterparts, which take priority in method call syntax. The trait allows using it in generic code.
+/// ```
+/// use {cadd::ops::SaturatingAbs, std::num::NonZero};
+///
+/// fn magnitude<T: SaturatingAbs>(value: T) -> T {
+///     value.saturating_abs()
+/// }
+///
+/// assert_eq!(magnitude(-5i32), 5);
+/// assert_eq!(magnitude(NonZero::<i32>::MIN), NonZero::<i32>::MAX);
+/// ```
+pub trait SaturatingAbs: Sized {
+    #[allow(missing_docs)]
+    fn saturating_abs(self) -> Self;
+}
+
+/// Placing and extracting bit fields in unsigned integers.
+///
+/// These are checked alternatives to `value << offset` and `(value >> offset) & mask`
+/// for packing fields into registers and protocol words.
+/// ```
+/// use cadd::ops::BitField;
+///
+/// let word = 0b101u16.place_at(4).unwrap() | 0b11u16.place_at(0).unwrap();
+/// assert_eq!(word, 0b101_0011);
+/// assert_eq!(word.extract_at(4, 3).unwrap(), 0b101);
+/// assert_eq!(
+///     0b11u8.place_at(7).unwrap_err().message(),
+///     "field doesn't fit: place_at(3, 7) for u8",
+/// );
+/// assert!(word.extract_at(14, 3).is_err());
+/// ```
+pub trait BitField: Sized {
+    /// Shifts the value left by `bit_offset`.
+    ///
+    /// Returns an error if `bit_offset` is greater or equal to the number of bits in the type
+    /// or if any set bits would be shifted out.
+    fn place_at(self, bit_offset: u32) -> crate::Result<Self>;
+
+    /// Returns `bits` bits of the value starting from `bit_offset`, shifted down to bit 0.
+    ///
+    /// Returns an error if the field doesn't fit into the type, i.e. if `bit_offset + bits`
+    /// is greater than the number of bits in the type.
+    fn extract_at(self, bit_offset: u32, bits: u32) -> crate::Result<Self>;
+}
+
+/// Packs booleans into the bits of an unsigned integer: `bits[0]` becomes the least significant bit.
+///
+/// Returns an error if there are more booleans than bits in `T`.
+/// See [`unpack_bits`] for the reverse operation.
+/// ```
+/// use cadd::ops::pack_bits;
+///
+/// assert_eq!(pack_bits::<u8>(&[true, false, true]).unwrap(), 0b101);
+/// assert_eq!(
+///     pack_bits::<u8>(&[false; 9]).unwrap_err().message(),
+///     "too many bits to pack into u8: 9",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn pack_bits<T>(bits: &[bool]) -> crate::Result<T>
+where
+    T: BitField + crate::convert::SaturatingFrom<u8> + core::ops::BitOr<Output = T> + Copy,
+{
+    let mut value = T::saturating_from(0);
+    for (offset, &bit) in bits.iter().enumerate() {
+        let bit = T::saturating_from(u8::from(bit));
+        let placed = u32::try_from(offset)
+            .ok()
+            .and_then(|offset| bit.place_at(offset).ok());
+        let Some(placed) = placed else {
+            return Err(crate::error::error!(
+                "pack_bits",
+                "too many bits to pack into {}: {}",
+                core::any::type_name::<T>(),
+                bits.len(),
+            ));
+        };
+        value = value | placed;
+    }
+    Ok(value)
+}
+
+/// Unpacks the `N` least significant bits of an unsigned integer into booleans:
+/// the least significant bit becomes the first element.
+///
+/// If `N` is greater than the number of bits in `T`, the remaining elements are `false`.
+/// See [`pack_bits`] for the reverse operation.
+/// ```
+/// use cadd::ops::unpack_bits;
+///
+/// assert_eq!(unpack_bits::<u8, 4>(0b0101), [true, false, true, false]);
+/// ```
+#[inline]
+pub fn unpack_bits<T, const N: usize>(value: T) -> [bool; N]
+where
+    T: BitField + crate::convert::SaturatingFrom<u8> + PartialEq + Copy,
+{
+    let zero = T::saturating_from(0);
+    core::array::from_fn(|offset| {
+        u32::try_from(offset)
+            .ok()
+            .and_then(|offset| value.extract_at(offset, 1).ok())
+            .is_some_and(|bit| bit != zero)
+    })
+}
+
+/// Wrapping arithmetic on [`Wrapping`](core::num::Wrapping) values that also reports whether the result wrapped.
+///
+/// The returned value is the same as with plain `Wrapping` arithmetic (e.g. `a + b`).
+/// The flag is `true` if the mathematically correct result was out of bounds of the type.
+/// This is based on the `overflowing_*` family of functions, such as [`overflowing_add`](u32::overflowing_add).
+/// ```
+/// use {cadd::ops::WrappingReport, std::num::Wrapping};
+///
+/// assert_eq!(Wrapping(250u8).cadd_report(Wrapping(10)), (Wrapping(4), true));
+/// assert_eq!(Wrapping(250u8).cadd_report(Wrapping(5)), (Wrapping(255), false));
+/// assert_eq!(Wrapping(0u8).csub_report(Wrapping(1)), (Wrapping(255), true));
+/// ```
+#[allow(missing_docs)]
+pub trait WrappingReport: Sized {
+    fn cadd_report(self, b: Self) -> (Self, bool);
+    fn csub_report(self, b: Self) -> (Self, bool);
+    fn cmul_report(self, b: Self) -> (Self, bool);
+}
+
+/// Compares integers of any two primitive types by their mathematical values.
+///
+/// Unlike comparing after an `as` cast, it never overflows or wraps, so negative values are always
+/// less than unsigned values and large unsigned values are never mistaken for negative ones.
+/// ```
+/// use {cadd::ops::ccmp, std::cmp::Ordering};
+///
+/// assert_eq!(ccmp(-1i32, u64::MAX), Ordering::Less);
+/// assert_eq!(ccmp(u64::MAX, -1i32), Ordering::Greater);
+/// assert_eq!(ccmp(u128::MAX, i128::MAX), Ordering::Greater);
+/// assert_eq!(ccmp(5u8, 5i64), Ordering::Equal);
+/// ```
+#[inline]
+pub fn ccmp<A, B>(a: A, b: B) -> core::cmp::Ordering
+where
+    A: Copy,
+    B: Copy,
+    i128: crate::convert::SaturatingFrom<A> + crate::convert::SaturatingFrom<B>,
+    u128: crate::convert::SaturatingFrom<A> + crate::convert::SaturatingFrom<B>,
+{
+    use crate::convert::SaturatingFrom;
+
+    // Saturation preserves the order, and `i128` represents every value below `i128::MAX` exactly.
+    // If both values saturate to `i128::MAX`, they are non-negative and fit into `u128`.
+    let signed_a = i128::saturating_from(a);
+    let signed_b = i128::saturating_from(b);
+    if signed_a != i128::MAX || signed_b != i128::MAX {
+        return signed_a.cmp(&signed_b);
+    }
+    u128::saturating_from(a).cmp(&u128::saturating_from(b))
+}
+
+/// Applies a rate in basis points (1/10000) to an amount: `amount * bps / 10000`,
+/// rounded according to `mode`.
+///
+/// The result is computed exactly without floating point numbers, so it's suitable for money.
+/// The intermediate product can't overflow. An error is returned only if the result doesn't fit into `u64`,
+/// which is possible for rates above 100% (10000 basis points).
+/// ```
+/// use cadd::ops::{apply_basis_points, RoundingMode};
+///
+/// // 2.5% of 1999 cents.
+/// assert_eq!(apply_basis_points(1999, 250, RoundingMode::HalfEven).unwrap(), 50);
+/// assert_eq!(apply_basis_points(1999, 250, RoundingMode::Floor).unwrap(), 49);
+/// assert!(apply_basis_points(u64::MAX, 20_000, RoundingMode::Floor).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn apply_basis_points(amount: u64, bps: u32, mode: RoundingMode) -> crate::Result<u64> {
+    // Less than 2^96, so it can't overflow.
+    let product = u128::from(amount) * u128::from(bps);
+    let result = product.cdiv_round(10_000, mode)?;
+    match u64::try_from(result) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(crate::error::error!(
+            "basis_points",
+            "overflow: apply_basis_points({}, {})",
+            amount,
+            bps,
+        )),
+    }
+}
+
+/// Returns the percentage of `part` in `whole`, rounded down and clamped to 100.
+///
+/// The computation is exact and can't overflow, unlike `part * 100 / whole`.
+/// ```
+/// use {cadd::ops::percent_of, std::num::NonZero};
+///
+/// let whole = NonZero::new(3).unwrap();
+/// assert_eq!(percent_of(2, whole), 66);
+/// assert_eq!(percent_of(5, whole), 100);
+/// ```
+#[inline]
+pub fn percent_of(part: u64, whole: core::num::NonZero<u64>) -> u8 {
+    // Less than 2^71, so it can't overflow.
+    let percent = u128::from(part) * 100 / u128::from(whole.get());
+    crate::convert::SaturatingFrom::saturating_from(percent.min(100))
+}
+
+macro_rules! declare_const_ops {
+    ($($ty:ident: $add_fn:ident, $mul_fn:ident;)*) => {
+        $(
+            #[doc = concat!("Addition of a constant: `a + B` for `", stringify!($ty), "`. Returns an error on overflow.
+
+The bound is computed at compile time, so the check is a single comparison with a constant.
+The result is the same as with [`cadd`]. When the operand of `cadd` is a literal, the optimizer usually
+produces the same code for it, so this function mostly makes the intent explicit.
+See also [`", stringify!($mul_fn), "`].
+```
+use cadd::ops::", stringify!($add_fn), ";
+
+assert_eq!(", stringify!($add_fn), "::<3>(2).unwrap(), 5);
+assert_eq!(
+    ", stringify!($add_fn), "::<1>(", stringify!($ty), "::MAX).unwrap_err().message(),
+    format!(\"overflow: {} + 1\", ", stringify!($ty), "::MAX),
+);
+```")]
+            #[inline]
+            #[track_caller]
+            pub fn $add_fn<const B: $ty>(a: $ty) -> crate::Result<$ty> {
+                if a > const { $ty::MAX - B } {
+                    return Err(crate::error::error!("add", "overflow: {:?} + {:?}", a, B));
+                }
+                Ok(a + B)
+            }
+
+            #[doc = concat!("Multiplication by a constant: `a * B` for `", stringify!($ty), "`. Returns an error on overflow.
+
+The bound `MAX / B` is computed at compile time, so the check is a single comparison with a constant
+instead of a multiplication with an overflow check as in [`cmul`]. The result is the same as with `cmul`.
+See also [`", stringify!($add_fn), "`].
+```
+use cadd::ops::", stringify!($mul_fn), ";
+
+assert_eq!(", stringify!($mul_fn), "::<3>(2).unwrap(), 6);
+assert!(", stringify!($mul_fn), "::<2>(", stringify!($ty), "::MAX).is_err());
+```")]
+            #[inline]
+            #[track_caller]
+            pub fn $mul_fn<const B: $ty>(a: $ty) -> crate::Result<$ty> {
+                let limit = const {
+                    if B == 0 {
+                        $ty::MAX
+                    } else {
+                        $ty::MAX / B
+                    }
+                };
+                if a > limit {
+                    return Err(crate::error::error!("mul", "overflow: {:?} * {:?}", a, B));
+                }
+                Ok(a * B)
+            }
+        )*
+    };
+}
+
+declare_const_ops!(
+    u32: cadd_const_u32, cmul_const_u32;
+    u64: cadd_const_u64, cmul_const_u64;
+);
+
+/// Implements checked operations for a newtype wrapper around an integer.
+///
+/// `impl_checked_ops!(Wrapper => Inner)` implements [`Cadd`], [`Csub`], [`Cmul`], [`Cdiv`], [`Crem`],
+/// [`CdivEuclid`], [`CremEuclid`] and [`Cneg`] for `Wrapper` by applying the operation
+/// to the inner values and wrapping the result. `Wrapper` must be a tuple struct with a single field of type `Inner`
+/// accessible from the place of the macro invocation.
+///
+/// Error messages are prefixed with the name of the newtype. The [category](crate::Error::category)
+/// of the error is preserved.
+/// ```
+/// use cadd::ops::{Cadd, Cdiv};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Meters(i64);
+///
+/// cadd::impl_checked_ops!(Meters => i64);
+///
+/// assert_eq!(Meters(2).cadd(Meters(3)).unwrap(), Meters(5));
+/// let err = Meters(i64::MAX).cadd(Meters(1)).unwrap_err();
+/// assert_eq!(err.message(), "Meters: overflow: 9223372036854775807 + 1");
+/// assert_eq!(err.category(), "add");
+/// assert_eq!(Meters(1).cdiv(Meters(0)).unwrap_err().message(), "Meters: division by zero: 1 / 0");
+/// ```
+#[macro_export]
+macro_rules! impl_checked_ops {
+    ($ty:ident => $inner:ty) => {
+        $crate::impl_checked_ops!(@binary $ty, $inner, Cadd, cadd);
+        $crate::impl_checked_ops!(@binary $ty, $inner, Csub, csub);
+        $crate::impl_checked_ops!(@binary $ty, $inner, Cmul, cmul);
+        $crate::impl_checked_ops!(@binary $ty, $inner, Cdiv, cdiv);
+        $crate::impl_checked_ops!(@binary $ty, $inner, Crem, crem);
+        $crate::impl_checked_ops!(@binary $ty, $inner, CdivEuclid, cdiv_euclid);
+        $crate::impl_checked_ops!(@binary $ty, $inner, CremEuclid, crem_euclid);
+        $crate::impl_checked_ops!(@unary $ty, $inner, Cneg, cneg);
+    };
+    (@binary $ty:ident, $inner:ty, $trait_:ident, $trait_fn:ident) => {
+        impl $crate::ops::$trait_ for $ty {
+            type Output = $ty;
+            type Error = $crate::Error;
+            #[inline]
+            #[track_caller]
+            fn $trait_fn(self, b: $ty) -> $crate::Result<$ty> {
+                match <$inner as $crate::ops::$trait_>::$trait_fn(self.0, b.0) {
+                    Ok(value) => Ok($ty(value)),
+                    Err(err) => Err($crate::impl_checked_ops!(@error $ty, err)),
+                }
+            }
+        }
+    };
+    (@unary $ty:ident, $inner:ty, $trait_:ident, $trait_fn:ident) => {
+        impl $crate::ops::$trait_ for $ty {
+            type Output = $ty;
+            type Error = $crate::Error;
+            #[inline]
+            #[track_caller]
+            fn $trait_fn(self) -> $crate::Result<$ty> {
+                match <$inner as $crate::ops::$trait_>::$trait_fn(self.0) {
+                    Ok(value) => Ok($ty(value)),
+                    Err(err) => Err($crate::impl_checked_ops!(@error $ty, err)),
+                }
+            }
+        }
+    };
+    (@error $ty:ident, $err:ident) => {
+        $crate::Error::from_args(
+            $err.category(),
+            ::core::format_args!("{}: {}", ::core::stringify!($ty), $err.message()),
+        )
+    };
+}
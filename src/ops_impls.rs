@@ -1,38 +1,57 @@
 #[cfg(feature = "std")]
 use std::time::{Instant, SystemTime};
 use {
-    alloc::format,
-    core::{num::NonZero, time::Duration},
+    crate::{
+        error::error,
+        ops::{Cadd, Cdiv, Cmul, RoundingMode},
+    },
+    core::{
+        num::{NonZero, Wrapping},
+        time::Duration,
+    },
 };
 
+// Error category is the function name without the "c" prefix, e.g. "add" for `cadd`.
+macro_rules! category {
+    ($trait_fn:ident) => {
+        &stringify!($trait_fn)[1..]
+    };
+}
+
 macro_rules! impl_binary_op {
     ($trait_:ident, $trait_fn:ident, $source_fn:ident, msg=$msg:literal for $t1:ty, $t2:ty, $out:ty) => {
         impl $crate::ops::$trait_<$t2> for $t1 {
             type Output = $out;
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn $trait_fn(self, b: $t2) -> $crate::Result<$out> {
-                self.$source_fn(b)
-                    .ok_or_else(|| crate::Error::new(format!($msg, self, b)))
+                match self.$source_fn(b) {
+                    Some(value) => Ok(value),
+                    None => Err(error!(category!($trait_fn), $msg, self, b)),
+                }
             }
         }
     };
-    ($trait_:ident, $trait_fn:ident, $source_fn:ident, err=$err:expr, for $t1:ty, $t2:ty, $out:ty) => {
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, reason=$reason:expr, msg=$msg:literal for $t1:ty, $t2:ty, $out:ty) => {
         impl $crate::ops::$trait_<$t2> for $t1 {
             type Output = $out;
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn $trait_fn(self, b: $t2) -> $crate::Result<$out> {
-                self.$source_fn(b)
-                    .ok_or_else(|| crate::Error::new(($err)(self, b)))
+                match self.$source_fn(b) {
+                    Some(value) => Ok(value),
+                    None => Err(error!(category!($trait_fn), $msg, ($reason)(self, b), self, b)),
+                }
             }
         }
     };
     ($trait_:ident, $trait_fn:ident, $source_fn:ident, msg=$msg:literal for $t1:ty) => {
         impl_binary_op!($trait_, $trait_fn, $source_fn, msg=$msg for $t1, $t1, $t1);
     };
-    ($trait_:ident, $trait_fn:ident, $source_fn:ident, err=$err:expr, for $t1:ty) => {
-        impl_binary_op!($trait_, $trait_fn, $source_fn, err=$err, for $t1, $t1, $t1);
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, reason=$reason:expr, msg=$msg:literal for $t1:ty) => {
+        impl_binary_op!($trait_, $trait_fn, $source_fn, reason=$reason, msg=$msg for $t1, $t1, $t1);
     };
 }
 
@@ -42,9 +61,9 @@ macro_rules! impl_binary_ops {
             impl_binary_op!($trait_, $trait_fn, $source_fn, msg=$msg for $($t1)*);
         )*
     };
-    ($trait_:ident, $trait_fn:ident, $source_fn:ident, err=$err:expr, for $(($($t1:tt)*),)+) => {
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, reason=$reason:expr, msg=$msg:literal for $(($($t1:tt)*),)+) => {
         $(
-            impl_binary_op!($trait_, $trait_fn, $source_fn, err=$err, for $($t1)*);
+            impl_binary_op!($trait_, $trait_fn, $source_fn, reason=$reason, msg=$msg for $($t1)*);
         )*
     };
 }
@@ -55,28 +74,34 @@ macro_rules! impl_unary_op {
             type Output = $out;
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn $trait_fn(self) -> $crate::Result<$out> {
-                self.$source_fn()
-                    .ok_or_else(|| crate::Error::new(format!($msg, self)))
+                match self.$source_fn() {
+                    Some(value) => Ok(value),
+                    None => Err(error!(category!($trait_fn), $msg, self)),
+                }
             }
         }
     };
-    ($trait_:ident, $trait_fn:ident, $source_fn:ident, err=$err:expr, for $t1:ty, $out:ty) => {
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, reason=$reason:expr, msg=$msg:literal for $t1:ty, $out:ty) => {
         impl $crate::ops::$trait_ for $t1 {
             type Output = $out;
             type Error = $crate::Error;
             #[inline]
+            #[track_caller]
             fn $trait_fn(self) -> $crate::Result<$out> {
-                self.$source_fn()
-                    .ok_or_else(|| crate::Error::new(($err)(self)))
+                match self.$source_fn() {
+                    Some(value) => Ok(value),
+                    None => Err(error!(category!($trait_fn), $msg, ($reason)(self), self)),
+                }
             }
         }
     };
     ($trait_:ident, $trait_fn:ident, $source_fn:ident, msg=$msg:literal for $t1:ty) => {
         impl_unary_op!($trait_, $trait_fn, $source_fn, msg=$msg for $t1, $t1);
     };
-    ($trait_:ident, $trait_fn:ident, $source_fn:ident, err=$err:expr, for $t1:ty) => {
-        impl_unary_op!($trait_, $trait_fn, $source_fn, err=$err, for $t1, $t1);
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, reason=$reason:expr, msg=$msg:literal for $t1:ty) => {
+        impl_unary_op!($trait_, $trait_fn, $source_fn, reason=$reason, msg=$msg for $t1, $t1);
     };
 }
 
@@ -86,9 +111,9 @@ macro_rules! impl_unary_ops {
             impl_unary_op!($trait_, $trait_fn, $source_fn, msg=$msg for $($t1)*);
         )*
     };
-    ($trait_:ident, $trait_fn:ident, $source_fn:ident, err=$err:expr, for $(($($t1:tt)*),)+) => {
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, reason=$reason:expr, msg=$msg:literal for $(($($t1:tt)*),)+) => {
         $(
-            impl_unary_op!($trait_, $trait_fn, $source_fn, err=$err, for $($t1)*);
+            impl_unary_op!($trait_, $trait_fn, $source_fn, reason=$reason, msg=$msg for $($t1)*);
         )*
     };
 }
@@ -134,6 +159,55 @@ impl_binary_ops!(
     (isize, usize, isize),
 );
 
+// Mixed `NonZero` and plain integer operands. The sum is non-zero regardless of the order,
+// but the product may be zero, so it's a plain integer.
+macro_rules! impl_non_zero_mixed_ops {
+    ($($t:ty,)*) => {
+        $(
+            impl $crate::ops::Cadd<NonZero<$t>> for $t {
+                type Output = NonZero<$t>;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cadd(self, b: NonZero<$t>) -> $crate::Result<NonZero<$t>> {
+                    match b.checked_add(self) {
+                        Some(value) => Ok(value),
+                        None => Err(error!("add", "overflow: {:?} + {:?}", self, b)),
+                    }
+                }
+            }
+
+            impl $crate::ops::Cmul<NonZero<$t>> for $t {
+                type Output = $t;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cmul(self, b: NonZero<$t>) -> $crate::Result<$t> {
+                    match self.checked_mul(b.get()) {
+                        Some(value) => Ok(value),
+                        None => Err(error!("mul", "overflow: {:?} * {:?}", self, b)),
+                    }
+                }
+            }
+
+            impl $crate::ops::Cmul<$t> for NonZero<$t> {
+                type Output = $t;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cmul(self, b: $t) -> $crate::Result<$t> {
+                    match self.get().checked_mul(b) {
+                        Some(value) => Ok(value),
+                        None => Err(error!("mul", "overflow: {:?} * {:?}", self, b)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_mixed_ops!(u8, u16, u32, u64, u128, usize,);
+
 impl_binary_ops!(
     Csub, csub, checked_sub, msg="overflow: {:?} - {:?}"
     for (u8), (i8), (u16), (i16), (u32), (i32), (u64), (i64), (u128), (i128), (usize), (isize),
@@ -173,58 +247,92 @@ impl_unary_ops!(
 );
 
 impl_binary_ops!(
-    Cdiv, cdiv, checked_div, err=|a, b| {
-        if b == 0 {
-            format!("division by zero: {a:?} / {b:?}")
-        } else {
-            format!("overflow: {a:?} / {b:?}")
-        }
-    },
+    Cdiv, cdiv, checked_div,
+    reason=|_, b| if b == 0 { "division by zero" } else { "overflow" },
+    msg="{}: {:?} / {:?}"
     for (u8), (i8), (u16), (i16), (u32), (i32), (u64), (i64), (u128), (i128), (usize), (isize),
     (Duration, u32, Duration),
 );
 
 impl_binary_ops!(
-    CdivEuclid, cdiv_euclid, checked_div_euclid, err=|a, b| {
-        if b == 0 {
-            format!("division by zero: div_euclid({a:?}, {b:?})")
-        } else {
-            format!("overflow: div_euclid({a:?}, {b:?})")
-        }
-    },
+    CdivEuclid, cdiv_euclid, checked_div_euclid,
+    reason=|_, b| if b == 0 { "division by zero" } else { "overflow" },
+    msg="{}: div_euclid({:?}, {:?})"
     for (u8), (i8), (u16), (i16), (u32), (i32), (u64), (i64), (u128), (i128), (usize), (isize),
 );
 
 impl_binary_ops!(
-    Crem, crem, checked_rem, err=|a, b| {
-        if b == 0 {
-            format!("division by zero: {a:?} % {b:?}")
-        } else {
-            format!("overflow: {a:?} % {b:?}")
-        }
-    },
+    Crem, crem, checked_rem,
+    reason=|_, b| if b == 0 { "division by zero" } else { "overflow" },
+    msg="{}: {:?} % {:?}"
     for (u8), (i8), (u16), (i16), (u32), (i32), (u64), (i64), (u128), (i128), (usize), (isize),
 );
 
 impl_binary_ops!(
-    CremEuclid, crem_euclid, checked_rem_euclid, err=|a, b| {
-        if b == 0 {
-            format!("division by zero: rem_euclid({a:?}, {b:?})")
-        } else {
-            format!("overflow: rem_euclid({a:?}, {b:?})")
-        }
-    },
+    CremEuclid, crem_euclid, checked_rem_euclid,
+    reason=|_, b| if b == 0 { "division by zero" } else { "overflow" },
+    msg="{}: rem_euclid({:?}, {:?})"
     for (u8), (i8), (u16), (i16), (u32), (i32), (u64), (i64), (u128), (i128), (usize), (isize),
 );
 
+// Operations on references delegate to the by-value implementations,
+// so that slices can be folded without `.copied()`.
+macro_rules! impl_ref_binary_ops {
+    (@impl $t1:ty => $($trait_:ident, $trait_fn:ident;)*) => {
+        $(
+            impl $crate::ops::$trait_<&$t1> for $t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn $trait_fn(self, b: &$t1) -> $crate::Result<$t1> {
+                    self.$trait_fn(*b)
+                }
+            }
+
+            impl $crate::ops::$trait_<$t1> for &$t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn $trait_fn(self, b: $t1) -> $crate::Result<$t1> {
+                    (*self).$trait_fn(b)
+                }
+            }
+
+            impl $crate::ops::$trait_<&$t1> for &$t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn $trait_fn(self, b: &$t1) -> $crate::Result<$t1> {
+                    (*self).$trait_fn(*b)
+                }
+            }
+        )*
+    };
+    ($($t1:ty,)*) => {
+        $(
+            impl_ref_binary_ops!(
+                @impl $t1 =>
+                Cadd, cadd;
+                Csub, csub;
+                Cmul, cmul;
+                Cdiv, cdiv;
+                Crem, crem;
+                CdivEuclid, cdiv_euclid;
+                CremEuclid, crem_euclid;
+            );
+        )*
+    };
+}
+
+impl_ref_binary_ops!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
 impl_binary_ops!(
-    CILog, cilog, checked_ilog, err=|a, b| {
-        if b < 2 {
-            format!("base is less than 2: ilog({a}, {b})")
-        } else {
-            format!("number is not positive: ilog({a}, {b})")
-        }
-    },
+    CILog, cilog, checked_ilog,
+    reason=|_, b| if b < 2 { "base is less than 2" } else { "number is not positive" },
+    msg="{}: ilog({}, {})"
     for
     (u8, u8, u32),
     (u16, u16, u32),
@@ -240,6 +348,34 @@ impl_binary_ops!(
     (isize, isize, u32),
 );
 
+// The base is only guaranteed to be non-zero, so base 1 (and negative bases) are still rejected.
+macro_rules! impl_ilog_non_zero_base {
+    ($($t:ty,)*) => {
+        $(
+            impl $crate::ops::CILog<NonZero<$t>> for $t {
+                type Output = u32;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cilog(self, b: NonZero<$t>) -> $crate::Result<u32> {
+                    match self.checked_ilog(b.get()) {
+                        Some(value) => Ok(value),
+                        None => Err(error!(
+                            "ilog",
+                            "{}: ilog({}, {})",
+                            if b.get() < 2 { "base is less than 2" } else { "number is not positive" },
+                            self,
+                            b,
+                        )),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_ilog_non_zero_base!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,);
+
 impl_unary_ops!(
     CILog2, cilog2, checked_ilog2, msg="number is not positive: ilog2({})"
     for
@@ -308,6 +444,106 @@ impl_binary_ops!(
     (isize, u32, isize),
 );
 
+macro_rules! impl_lossless_shift {
+    ($trait_:ident, $trait_fn:ident, $source_fn:ident, $reverse_op:tt, $op:literal for $($t1:ty,)*) => {
+        $(
+            impl $crate::ops::$trait_<u32> for $t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn $trait_fn(self, b: u32) -> $crate::Result<$t1> {
+                    let Some(value) = self.$source_fn(b) else {
+                        return Err(error!(category!($trait_fn), "shift amount is too large: {} {} {}", self, $op, b));
+                    };
+                    if (value $reverse_op b) == self {
+                        Ok(value)
+                    } else {
+                        Err(error!(category!($trait_fn), "shift loses bits: {} {} {}", self, $op, b))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_non_zero_shl {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::Cshl<u32> for NonZero<$t1> {
+                type Output = NonZero<$t1>;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cshl(self, b: u32) -> $crate::Result<NonZero<$t1>> {
+                    let Some(value) = self.get().checked_shl(b) else {
+                        return Err(error!("shl", "shift amount is too large: {} << {}", self, b));
+                    };
+                    match NonZero::new(value) {
+                        Some(value) => Ok(value),
+                        None => Err(error!("shl", "result is zero: {} << {}", self, b)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_shl!(u8, u16, u32, u64, u128, usize,);
+
+macro_rules! impl_bit_field {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::BitField for $t1 {
+                #[inline]
+                #[track_caller]
+                fn place_at(self, bit_offset: u32) -> $crate::Result<$t1> {
+                    match self.checked_shl(bit_offset) {
+                        Some(value) if value >> bit_offset == self => Ok(value),
+                        _ => Err(error!(
+                            "place_at",
+                            "field doesn't fit: place_at({}, {}) for {}",
+                            self,
+                            bit_offset,
+                            stringify!($t1),
+                        )),
+                    }
+                }
+
+                #[inline]
+                #[track_caller]
+                fn extract_at(self, bit_offset: u32, bits: u32) -> $crate::Result<$t1> {
+                    if !bit_offset.checked_add(bits).is_some_and(|end| end <= <$t1>::BITS) {
+                        return Err(error!(
+                            "extract_at",
+                            "field is out of bounds: extract_at({}, {}, {}) for {}",
+                            self,
+                            bit_offset,
+                            bits,
+                            stringify!($t1),
+                        ));
+                    }
+                    // `bits` and `bit_offset` can be equal to `BITS` here, so the shifts must be checked.
+                    let value = self.checked_shr(bit_offset).unwrap_or(0);
+                    let mask = <$t1>::MAX.checked_shr(<$t1>::BITS - bits).unwrap_or(0);
+                    Ok(value & mask)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_field!(u8, u16, u32, u64, u128, usize,);
+
+impl_lossless_shift!(
+    CshlLossless, cshl_lossless, checked_shl, >>, "<<"
+    for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+);
+impl_lossless_shift!(
+    CshrLossless, cshr_lossless, checked_shr, <<, ">>"
+    for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+);
+
 impl_binary_ops!(
     Cpow, cpow, checked_pow, msg="overflow: pow({}, {})"
     for
@@ -323,8 +559,89 @@ impl_binary_ops!(
     (i64, u32, i64),
     (i128, u32, i128),
     (isize, u32, isize),
+    (NonZero<u8>, u32, NonZero<u8>),
+    (NonZero<u16>, u32, NonZero<u16>),
+    (NonZero<u32>, u32, NonZero<u32>),
+    (NonZero<u64>, u32, NonZero<u64>),
+    (NonZero<u128>, u32, NonZero<u128>),
+    (NonZero<usize>, u32, NonZero<usize>),
+    (NonZero<i8>, u32, NonZero<i8>),
+    (NonZero<i16>, u32, NonZero<i16>),
+    (NonZero<i32>, u32, NonZero<i32>),
+    (NonZero<i64>, u32, NonZero<i64>),
+    (NonZero<i128>, u32, NonZero<i128>),
+    (NonZero<isize>, u32, NonZero<isize>),
+);
+
+macro_rules! impl_pow_widening {
+    ($(($t1:ty, $out:ty),)*) => {
+        $(
+            impl $crate::ops::CpowWidening<u32> for $t1 {
+                type Output = $out;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cpow_widening(self, b: u32) -> $crate::Result<$out> {
+                    match <$out>::from(self).checked_pow(b) {
+                        Some(value) => Ok(value),
+                        None => Err(error!(
+                            "pow_widening",
+                            "overflow: pow({}, {}) as {}",
+                            self,
+                            b,
+                            ::core::any::type_name::<$out>(),
+                        )),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_pow_widening!(
+    (u8, u16),
+    (u16, u32),
+    (u32, u64),
+    (u64, u128),
+    (i8, i16),
+    (i16, i32),
+    (i32, i64),
+    (i64, i128),
 );
 
+// `powi` and `powf` are not available in `core`.
+#[cfg(feature = "std")]
+macro_rules! impl_float_pow {
+    ($source_fn:ident for $t1:ty, $t2:ty) => {
+        impl $crate::ops::Cpow<$t2> for $t1 {
+            type Output = $t1;
+            type Error = $crate::Error;
+            #[inline]
+            #[track_caller]
+            fn cpow(self, b: $t2) -> $crate::Result<$t1> {
+                let value = self.$source_fn(b);
+                if value.is_finite() {
+                    Ok(value)
+                } else {
+                    Err(error!(
+                        "pow",
+                        "result is not finite: pow({:?}, {:?})", self, b
+                    ))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float_pow!(powi for f32, i32);
+#[cfg(feature = "std")]
+impl_float_pow!(powi for f64, i32);
+#[cfg(feature = "std")]
+impl_float_pow!(powf for f32, f32);
+#[cfg(feature = "std")]
+impl_float_pow!(powf for f64, f64);
+
 impl_unary_ops!(
     Cabs, cabs, checked_abs, msg="overflow: abs({})"
     for
@@ -333,13 +650,9 @@ impl_unary_ops!(
 );
 
 impl_binary_ops!(
-    CnextMultipleOf, cnext_multiple_of, checked_next_multiple_of, err=|a, b| {
-        if b == 0 {
-            format!("multiplier is zero: next_multiple_of({a}, {b})")
-        } else {
-            format!("overflow: next_multiple_of({a}, {b})")
-        }
-    },
+    CnextMultipleOf, cnext_multiple_of, checked_next_multiple_of,
+    reason=|_, b| if b == 0 { "multiplier is zero" } else { "overflow" },
+    msg="{}: next_multiple_of({}, {})"
     for (u8), (u16), (u32), (u64), (u128), (usize),
 );
 
@@ -348,3 +661,325 @@ impl_unary_ops!(
     for (u8), (u16), (u32), (u64), (u128), (usize),
     (NonZero<u8>), (NonZero<u16>), (NonZero<u32>), (NonZero<u64>), (NonZero<u128>), (NonZero<usize>),
 );
+
+#[cfg(feature = "std")]
+impl crate::ops::Distance for Instant {
+    type Output = Duration;
+    type Error = crate::Error;
+    #[inline]
+    #[track_caller]
+    fn distance(self, b: Instant) -> crate::Result<Duration> {
+        let (later, earlier) = if self >= b { (self, b) } else { (b, self) };
+        match later.checked_duration_since(earlier) {
+            Some(value) => Ok(value),
+            None => Err(error!("distance", "overflow: distance({self:?}, {b:?})")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::ops::Distance for SystemTime {
+    type Output = Duration;
+    type Error = crate::Error;
+    #[inline]
+    fn distance(self, b: SystemTime) -> crate::Result<Duration> {
+        // If `b` is later than `self`, the error contains the positive difference.
+        Ok(self.duration_since(b).unwrap_or_else(|err| err.duration()))
+    }
+}
+
+macro_rules! impl_div_round {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::CdivRound for $t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                #[allow(unused_comparisons)]
+                fn cdiv_round(self, b: $t1, mode: RoundingMode) -> $crate::Result<$t1> {
+                    let quotient = self.cdiv(b)?;
+                    // Can't overflow because `cdiv` succeeded.
+                    let remainder = self % b;
+                    if remainder == 0 {
+                        return Ok(quotient);
+                    }
+                    // `|b| >= 2` here, so `quotient` is at least 1 away from the type bounds.
+                    let negative = (self < 0) != (b < 0);
+                    let away_from_zero = if negative { quotient - 1 } else { quotient + 1 };
+                    let remainder = remainder.abs_diff(0);
+                    let complement = b.abs_diff(0) - remainder;
+                    let round_away = match mode {
+                        RoundingMode::TowardZero => false,
+                        RoundingMode::AwayFromZero => true,
+                        RoundingMode::Floor => negative,
+                        RoundingMode::Ceil => !negative,
+                        RoundingMode::HalfAwayFromZero => remainder >= complement,
+                        RoundingMode::HalfEven => {
+                            remainder > complement || (remainder == complement && quotient % 2 != 0)
+                        }
+                    };
+                    Ok(if round_away { away_from_zero } else { quotient })
+                }
+            }
+        )*
+    };
+}
+
+impl_div_round!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+macro_rules! impl_div_pow2 {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::CdivPow2<u32> for $t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                #[allow(unused_comparisons)]
+                fn cdiv_pow2(self, b: u32) -> $crate::Result<$t1> {
+                    let Some(quotient) = self.checked_shr(b) else {
+                        return Err(error!(
+                            "div_pow2",
+                            "shift amount is too large: div_pow2({}, {})",
+                            self,
+                            b
+                        ));
+                    };
+                    // Arithmetic shift rounds toward negative infinity, but division rounds toward zero.
+                    // `quotient` is negative here, so adding 1 can't overflow.
+                    if self < 0 && quotient << b != self {
+                        Ok(quotient + 1)
+                    } else {
+                        Ok(quotient)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_div_pow2!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+macro_rules! impl_div_exact {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::CdivExact for $t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                #[track_caller]
+                fn cdiv_exact(self, b: $t1) -> $crate::Result<$t1> {
+                    let quotient = self.cdiv(b)?;
+                    // Can't overflow because `cdiv` succeeded.
+                    let remainder = self % b;
+                    if remainder != 0 {
+                        return Err(error!(
+                            "div_exact",
+                            "division is not exact: {:?} / {:?} (remainder {:?})",
+                            self,
+                            b,
+                            remainder,
+                        ));
+                    }
+                    Ok(quotient)
+                }
+            }
+        )*
+    };
+}
+
+impl_div_exact!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+macro_rules! impl_bounded {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::Cbounded for $t1 {
+                #[inline]
+                #[track_caller]
+                fn cadd_bounded(self, b: $t1, max: $t1) -> $crate::Result<$t1> {
+                    let value = self.cadd(b)?;
+                    if value > max {
+                        return Err(error!("limit", "exceeds limit {:?}: {:?}", max, value));
+                    }
+                    Ok(value)
+                }
+
+                #[inline]
+                #[track_caller]
+                fn cmul_bounded(self, b: $t1, max: $t1) -> $crate::Result<$t1> {
+                    let value = self.cmul(b)?;
+                    if value > max {
+                        return Err(error!("limit", "exceeds limit {:?}: {:?}", max, value));
+                    }
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+macro_rules! impl_option_ops {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::CaddOption for $t1 {
+                #[inline]
+                fn cadd_opt(self, b: $t1) -> Option<$t1> {
+                    self.checked_add(b)
+                }
+
+                #[inline]
+                fn csub_opt(self, b: $t1) -> Option<$t1> {
+                    self.checked_sub(b)
+                }
+
+                #[inline]
+                fn cmul_opt(self, b: $t1) -> Option<$t1> {
+                    self.checked_mul(b)
+                }
+
+                #[inline]
+                fn cdiv_opt(self, b: $t1) -> Option<$t1> {
+                    self.checked_div(b)
+                }
+            }
+        )*
+    };
+}
+
+impl_option_ops!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+macro_rules! impl_would_overflow {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::WouldOverflow for $t1 {
+                #[inline]
+                fn would_overflow_add(self, b: $t1) -> bool {
+                    self.overflowing_add(b).1
+                }
+                #[inline]
+                fn would_overflow_sub(self, b: $t1) -> bool {
+                    self.overflowing_sub(b).1
+                }
+                #[inline]
+                fn would_overflow_mul(self, b: $t1) -> bool {
+                    self.overflowing_mul(b).1
+                }
+                #[inline]
+                fn would_overflow_pow(self, exp: u32) -> bool {
+                    self.overflowing_pow(exp).1
+                }
+                #[inline]
+                fn would_overflow_neg(self) -> bool {
+                    self.overflowing_neg().1
+                }
+            }
+        )*
+    };
+}
+
+impl_would_overflow!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
+
+macro_rules! impl_sub_or_zero {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::CsubOrZero for $t1 {
+                #[inline]
+                fn csub_or_zero(self, b: $t1) -> $t1 {
+                    self.saturating_sub(b)
+                }
+            }
+        )*
+    };
+}
+
+impl_sub_or_zero!(u8, u16, u32, u64, u128, usize,);
+
+macro_rules! impl_saturating_abs {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::SaturatingAbs for $t1 {
+                #[inline]
+                fn saturating_abs(self) -> $t1 {
+                    <$t1>::saturating_abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_abs!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    NonZero<i8>,
+    NonZero<i16>,
+    NonZero<i32>,
+    NonZero<i64>,
+    NonZero<i128>,
+    NonZero<isize>,
+);
+
+// Digit-by-digit cube root in base 2: every step determines one bit of the result
+// by consuming three bits of the input. Intermediate values never exceed the input.
+macro_rules! impl_icbrt {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::Cicbrt for $t1 {
+                type Output = $t1;
+                type Error = $crate::Error;
+                #[inline]
+                fn cicbrt(self) -> $crate::Result<$t1> {
+                    let mut remainder = self;
+                    let mut root: $t1 = 0;
+                    let mut shift = (<$t1>::BITS - 1) / 3 * 3;
+                    loop {
+                        root <<= 1;
+                        let step = 3 * root * (root + 1) + 1;
+                        if remainder >> shift >= step {
+                            remainder -= step << shift;
+                            root += 1;
+                        }
+                        if shift == 0 {
+                            return Ok(root);
+                        }
+                        shift -= 3;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_icbrt!(u8, u16, u32, u64, u128, usize,);
+
+macro_rules! impl_wrapping_report {
+    ($($t1:ty,)*) => {
+        $(
+            impl $crate::ops::WrappingReport for Wrapping<$t1> {
+                #[inline]
+                fn cadd_report(self, b: Self) -> (Self, bool) {
+                    let (value, overflow) = self.0.overflowing_add(b.0);
+                    (Wrapping(value), overflow)
+                }
+                #[inline]
+                fn csub_report(self, b: Self) -> (Self, bool) {
+                    let (value, overflow) = self.0.overflowing_sub(b.0);
+                    (Wrapping(value), overflow)
+                }
+                #[inline]
+                fn cmul_report(self, b: Self) -> (Self, bool) {
+                    let (value, overflow) = self.0.overflowing_mul(b.0);
+                    (Wrapping(value), overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping_report!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,);
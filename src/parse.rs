@@ -0,0 +1,318 @@
+//! Parsing numbers from strings.
+
+use {
+    crate::{convert::Cfrom, error::error},
+    core::{
+        any::type_name,
+        fmt::{self, Display, Write},
+        num::IntErrorKind,
+        str::FromStr,
+    },
+};
+
+/// Parses a value and checks that the string is in the canonical form of that value,
+/// i.e. that formatting the parsed value produces exactly the same string.
+///
+/// This rejects inputs like `"007"` or `"+5"` in formats that require a unique representation for every value.
+/// ```
+/// use cadd::parse::parse_canonical;
+///
+/// assert_eq!(parse_canonical::<u32>("7").unwrap(), 7);
+/// assert_eq!(
+///     parse_canonical::<u32>("007").unwrap_err().message(),
+///     "non-canonical literal \"007\" (canonical form is \"7\")",
+/// );
+/// assert!(parse_canonical::<i32>("+5").is_err());
+/// assert!(parse_canonical::<i32>("-0").is_err());
+/// ```
+#[track_caller]
+pub fn parse_canonical<T>(s: &str) -> crate::Result<T>
+where
+    T: FromStr + Display,
+    T::Err: Display,
+{
+    let value = match s.parse::<T>() {
+        Ok(value) => value,
+        Err(err) => {
+            return Err(error!(
+                "parse",
+                "cannot parse {:?} as {}: {}",
+                s,
+                type_name::<T>(),
+                err
+            ))
+        }
+    };
+    let mut matcher = PrefixMatcher { remaining: s };
+    if write!(matcher, "{value}").is_err() || !matcher.remaining.is_empty() {
+        return Err(error!(
+            "parse",
+            "non-canonical literal {:?} (canonical form is \"{}\")", s, value,
+        ));
+    }
+    Ok(value)
+}
+
+/// Parses an integer in the specified base (from 2 to 36), with an optional `+` or `-` sign.
+///
+/// Unlike `from_str_radix`, it's generic over the target type, doesn't panic on an invalid base
+/// and returns an error that includes the input and the base.
+/// ```
+/// use cadd::parse::cparse_radix;
+///
+/// assert_eq!(cparse_radix::<u8>("ff", 16).unwrap(), 255);
+/// assert_eq!(cparse_radix::<i8>("-80", 16).unwrap(), -128);
+/// assert_eq!(
+///     cparse_radix::<u8>("100", 16).unwrap_err().message(),
+///     "cannot parse \"100\" as u8 (base 16): number is out of bounds",
+/// );
+/// ```
+#[track_caller]
+pub fn cparse_radix<T>(s: &str, radix: u32) -> crate::Result<T>
+where
+    T: Cfrom<u128> + Cfrom<i128>,
+{
+    let (negative, digits) = split_sign(s);
+    parse_digits(s, negative, digits, radix)
+}
+
+/// Parses an integer with an optional base prefix: `0x` (hexadecimal), `0o` (octal) or `0b` (binary).
+/// Without a prefix, the number is parsed as decimal.
+///
+/// The sign (if any) must precede the prefix, e.g. `-0x10`. Uppercase prefixes (`0X`, `0O`, `0B`) are also accepted.
+/// ```
+/// use cadd::parse::cparse_prefixed;
+///
+/// assert_eq!(cparse_prefixed::<u32>("0x1F").unwrap(), 31);
+/// assert_eq!(cparse_prefixed::<u32>("0o17").unwrap(), 15);
+/// assert_eq!(cparse_prefixed::<i32>("-0b101").unwrap(), -5);
+/// assert_eq!(cparse_prefixed::<u32>("42").unwrap(), 42);
+/// assert_eq!(
+///     cparse_prefixed::<u32>("0b102").unwrap_err().message(),
+///     "cannot parse \"0b102\" as u32 (base 2): invalid digit found in string",
+/// );
+/// ```
+#[track_caller]
+pub fn cparse_prefixed<T>(s: &str) -> crate::Result<T>
+where
+    T: Cfrom<u128> + Cfrom<i128>,
+{
+    let (negative, rest) = split_sign(s);
+    let bytes = rest.as_bytes();
+    let radix = match bytes {
+        [b'0', b'x' | b'X', ..] => 16,
+        [b'0', b'o' | b'O', ..] => 8,
+        [b'0', b'b' | b'B', ..] => 2,
+        _ => return parse_digits(s, negative, rest, 10),
+    };
+    // The prefix is ASCII, so this is a char boundary.
+    parse_digits(s, negative, &rest[2..], radix)
+}
+
+/// Validation of digit groups in [`parse_int_grouped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// The first group has 1 to 3 digits and all other groups have exactly 3 digits, e.g. `1,234,567`.
+    Strict,
+    /// Groups can have any non-zero number of digits, e.g. `12,34,567`.
+    Lenient,
+}
+
+/// Parses a decimal integer with digits separated into groups, e.g. `"1,234,567"`,
+/// with an optional `+` or `-` sign.
+///
+/// The separator can't be at the start or at the end of the number, and two separators
+/// can't be adjacent. A number without separators is accepted in `Lenient` mode,
+/// and in `Strict` mode only if it has at most 3 digits.
+/// The separator can't be an ASCII digit, `+` or `-`.
+/// ```
+/// use cadd::parse::{parse_int_grouped, Grouping};
+///
+/// assert_eq!(parse_int_grouped::<u32>("1,234,567", ',', Grouping::Strict).unwrap(), 1_234_567);
+/// assert_eq!(parse_int_grouped::<i64>("-12 34", ' ', Grouping::Lenient).unwrap(), -1234);
+/// assert_eq!(
+///     parse_int_grouped::<u32>("12,34", ',', Grouping::Strict).unwrap_err().message(),
+///     "cannot parse \"12,34\" as u32: misplaced separator",
+/// );
+/// assert_eq!(
+///     parse_int_grouped::<u32>("1234", ',', Grouping::Strict).unwrap_err().message(),
+///     "cannot parse \"1234\" as u32: missing group separator",
+/// );
+/// ```
+#[track_caller]
+pub fn parse_int_grouped<T>(s: &str, separator: char, grouping: Grouping) -> crate::Result<T>
+where
+    T: Cfrom<u128> + Cfrom<i128>,
+{
+    if separator.is_ascii_digit() || matches!(separator, '+' | '-') {
+        return Err(error!(
+            "parse",
+            "cannot parse {:?} as {}: invalid separator {:?}",
+            s,
+            type_name::<T>(),
+            separator,
+        ));
+    }
+    let (negative, digits) = split_sign(s);
+    let reason = match grouped_magnitude(digits, separator, grouping) {
+        Ok(magnitude) => match apply_sign(negative, magnitude) {
+            Some(value) => return Ok(value),
+            None => "number is out of bounds",
+        },
+        Err(reason) => reason,
+    };
+    Err(error!(
+        "parse",
+        "cannot parse {:?} as {}: {}",
+        s,
+        type_name::<T>(),
+        reason
+    ))
+}
+
+fn grouped_magnitude(
+    digits: &str,
+    separator: char,
+    grouping: Grouping,
+) -> Result<u128, &'static str> {
+    if digits.is_empty() {
+        return Err("no digits");
+    }
+    let mut magnitude = 0u128;
+    for (index, group) in digits.split(separator).enumerate() {
+        if !group.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("invalid digit found in string");
+        }
+        let min_len = match grouping {
+            Grouping::Strict if index > 0 => 3,
+            _ => 1,
+        };
+        if group.len() < min_len {
+            return Err("misplaced separator");
+        }
+        if grouping == Grouping::Strict && group.len() > 3 {
+            return Err("missing group separator");
+        }
+        for digit in group.bytes() {
+            magnitude = match magnitude
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(u128::from(digit - b'0')))
+            {
+                Some(magnitude) => magnitude,
+                None => return Err("number is out of bounds"),
+            };
+        }
+    }
+    Ok(magnitude)
+}
+
+/// Converts a magnitude with a sign to `T`. Returns `None` if it's out of bounds.
+fn apply_sign<T>(negative: bool, magnitude: u128) -> Option<T>
+where
+    T: Cfrom<u128> + Cfrom<i128>,
+{
+    if !negative {
+        T::cfrom(magnitude).ok()
+    } else if magnitude <= i128::MIN.unsigned_abs() {
+        T::cfrom(0i128.wrapping_sub_unsigned(magnitude)).ok()
+    } else {
+        None
+    }
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+#[track_caller]
+fn parse_digits<T>(s: &str, negative: bool, digits: &str, radix: u32) -> crate::Result<T>
+where
+    T: Cfrom<u128> + Cfrom<i128>,
+{
+    let reason = if !(2..=36).contains(&radix) {
+        "base must be from 2 to 36"
+    } else if digits.is_empty() {
+        "no digits"
+    } else if digits.starts_with(['+', '-']) {
+        // `from_str_radix` accepts a sign, but it's already consumed.
+        "invalid digit found in string"
+    } else {
+        match u128::from_str_radix(digits, radix) {
+            Ok(magnitude) => match apply_sign(negative, magnitude) {
+                Some(value) => return Ok(value),
+                None => "number is out of bounds",
+            },
+            Err(err) => match err.kind() {
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => "number is out of bounds",
+                _ => "invalid digit found in string",
+            },
+        }
+    };
+    Err(error!(
+        "parse",
+        "cannot parse {:?} as {} (base {}): {}",
+        s,
+        type_name::<T>(),
+        radix,
+        reason
+    ))
+}
+
+/// Sets of strings accepted as `true` and `false` by [`parse_bool_with`].
+///
+/// Tokens are compared case-insensitively (ASCII only).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoolTokens<'a> {
+    /// Strings parsed as `true`.
+    pub truthy: &'a [&'a str],
+    /// Strings parsed as `false`.
+    pub falsy: &'a [&'a str],
+}
+
+impl BoolTokens<'static> {
+    /// Tokens accepted by the `Cfrom<&str>` implementation for `bool`:
+    /// `true`/`false`, `yes`/`no`, `on`/`off` and `1`/`0`.
+    pub const DEFAULT: Self = Self {
+        truthy: &["true", "yes", "on", "1"],
+        falsy: &["false", "no", "off", "0"],
+    };
+}
+
+/// Parses a boolean value using a custom vocabulary.
+///
+/// Returns an error if the string doesn't match any of the tokens.
+/// ```
+/// use cadd::parse::{parse_bool_with, BoolTokens};
+///
+/// let tokens = BoolTokens { truthy: &["y", "enabled"], falsy: &["n", "disabled"] };
+/// assert!(parse_bool_with("Enabled", &tokens).unwrap());
+/// assert!(!parse_bool_with("n", &tokens).unwrap());
+/// assert!(parse_bool_with("yes", &tokens).is_err());
+/// ```
+#[track_caller]
+pub fn parse_bool_with(s: &str, tokens: &BoolTokens<'_>) -> crate::Result<bool> {
+    let matches = |list: &[&str]| list.iter().any(|token| token.eq_ignore_ascii_case(s));
+    if matches(tokens.truthy) {
+        Ok(true)
+    } else if matches(tokens.falsy) {
+        Ok(false)
+    } else {
+        Err(error!("parse", "cannot parse {:?} as bool", s))
+    }
+}
+
+/// Consumes the expected string as the output is written, failing on the first mismatch.
+struct PrefixMatcher<'a> {
+    remaining: &'a str,
+}
+
+impl Write for PrefixMatcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.remaining = self.remaining.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,42 @@
-//! Exports most of the library's traits and functions.
+//! Exports most of the library's traits and functions, as well as [`Error`](crate::Error)
+//! and [`Result`](crate::Result).
+//!
+//! The exported `Result` has the same type parameters as `core::result::Result`, with the error type
+//! defaulting to [`Error`](crate::Error). It shadows the standard `Result`, but `Result<T, E>`
+//! still works as usual:
+//! ```
+//! use cadd::prelude::*;
+//!
+//! fn f(a: u32) -> Result<u32> {
+//!     a.cadd(1)
+//! }
+//!
+//! fn g(a: &str) -> Result<u32, std::num::ParseIntError> {
+//!     a.parse()
+//! }
+//! # assert!(f(u32::MAX).is_err());
+//! # assert!(g("x").is_err());
+//! ```
+//!
+//! See also [`funcs`](crate::funcs) and [`traits`](crate::traits) if you prefer to avoid wildcard imports.
 
 pub use crate::{
-    convert::{non_zero, Cfrom, Cinto, IntoType, SaturatingFrom, SaturatingInto, ToNonZero},
+    convert::{
+        non_zero, CTry, CencodeUtf8, Cfrom, Cinto, IntoType, NonZeroFromOps, ReinterpretFrom,
+        ReinterpretInto, SaturatingFrom, SaturatingInto, SwapBytes, ToBe, ToLe, ToNonZero,
+        ToNonZeroAbs,
+    },
     ops::{
-        cabs, cadd, cdiv, cdiv_euclid, cilog, cilog10, cilog2, cisqrt, cmul, cneg,
-        cnext_multiple_of, cnext_power_of_two, cpow, crem, crem_euclid, cshl, cshr, csub, CILog,
-        CILog10, CILog2, Cabs, Cadd, Cdiv, CdivEuclid, Cisqrt, Cmul, Cneg, CnextMultipleOf,
-        CnextPowerOfTwo, Cpow, Crem, CremEuclid, Cshl, Cshr, Csub,
+        apply_basis_points, cabs, cadd, cadd_const_u32, cadd_const_u64, ccmp, cdiv, cdiv_euclid,
+        cdiv_exact, cdiv_pow2, cdiv_round, cicbrt, cilog, cilog10, cilog2, cisqrt, cmul,
+        cmul_const_u32, cmul_const_u64, cneg, cnext_multiple_of, cnext_power_of_two, cpow,
+        cpow_widening, crem, crem_euclid, cshl, cshl_lossless, cshr, cshr_lossless, csub, distance,
+        pack_bits, percent_of, unpack_bits, BitField, CILog, CILog10, CILog2, Cabs, Cadd,
+        CaddOption, Cbounded, Cdiv, CdivEuclid, CdivExact, CdivPow2, CdivRound, Cicbrt, Cisqrt,
+        Cmul, Cneg, CnextMultipleOf, CnextPowerOfTwo, Cpow, CpowWidening, Crem, CremEuclid, Cshl,
+        CshlLossless, Cshr, CshrLossless, Csub, CsubOrZero, Distance, RoundingMode, SaturatingAbs,
+        WouldOverflow, WrappingReport,
     },
+    time::{CdivRemDuration, CoffsetNanos, CoffsetSecs, DurationUnit, DurationUnits},
+    Error, Result,
 };
@@ -0,0 +1,68 @@
+//! Checked operations on ranges and indices.
+
+use {
+    crate::error::error,
+    core::{num::NonZero, ops::Range},
+};
+
+/// Moves a range forward by `by`: `start + by..end + by`.
+///
+/// Returns an error if either bound overflows.
+/// ```
+/// use cadd::range::shift_range;
+///
+/// assert_eq!(shift_range(2..5, 10).unwrap(), 12..15);
+/// assert!(shift_range(2..usize::MAX, 1).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn shift_range(r: Range<usize>, by: usize) -> crate::Result<Range<usize>> {
+    match (r.start.checked_add(by), r.end.checked_add(by)) {
+        (Some(start), Some(end)) => Ok(start..end),
+        _ => Err(error!(
+            "shift_range",
+            "overflow: shift_range({:?}, {})", r, by
+        )),
+    }
+}
+
+/// Moves a range forward (if `by` is positive) or backward (if `by` is negative).
+///
+/// Returns an error if either bound overflows or goes below zero.
+/// ```
+/// use cadd::range::shift_range_signed;
+///
+/// assert_eq!(shift_range_signed(10..20, -5).unwrap(), 5..15);
+/// assert_eq!(shift_range_signed(10..20, 5).unwrap(), 15..25);
+/// assert!(shift_range_signed(10..20, -11).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn shift_range_signed(r: Range<usize>, by: isize) -> crate::Result<Range<usize>> {
+    match (r.start.checked_add_signed(by), r.end.checked_add_signed(by)) {
+        (Some(start), Some(end)) => Ok(start..end),
+        _ => Err(error!(
+            "shift_range",
+            "overflow: shift_range({:?}, {})", r, by
+        )),
+    }
+}
+
+/// Advances a position by a non-zero step: `pos + step`. Returns an error on overflow.
+///
+/// The `NonZero` step guarantees that a loop calling this function always makes progress.
+/// ```
+/// use {cadd::range::advance, std::num::NonZero};
+///
+/// let step = NonZero::new(4).unwrap();
+/// assert_eq!(advance(10, step).unwrap(), 14);
+/// assert!(advance(usize::MAX, step).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn advance(pos: usize, step: NonZero<usize>) -> crate::Result<usize> {
+    match pos.checked_add(step.get()) {
+        Some(pos) => Ok(pos),
+        None => Err(error!("advance", "overflow: advance({}, {})", pos, step)),
+    }
+}
@@ -0,0 +1,108 @@
+//! Checked string operations.
+//!
+//! Requires the `alloc` feature.
+
+use {crate::error::error, alloc::string::String};
+
+/// Concatenation: `a + b`.
+///
+/// Returns an error if the resulting length exceeds `isize::MAX` or if the allocation fails,
+/// instead of panicking or aborting.
+/// ```
+/// use cadd::string::cconcat;
+///
+/// assert_eq!(cconcat("Hello, ".into(), "world").unwrap(), "Hello, world");
+/// ```
+#[inline]
+#[track_caller]
+pub fn cconcat(mut a: String, b: &str) -> crate::Result<String> {
+    let len = match a.len().checked_add(b.len()) {
+        Some(len) if len <= isize::MAX as usize => len,
+        _ => {
+            return Err(error!(
+                "concat",
+                "string length overflow: {} + {}",
+                a.len(),
+                b.len()
+            ))
+        }
+    };
+    if let Err(err) = a.try_reserve(b.len()) {
+        return Err(error!(
+            "concat",
+            "cannot allocate string of length {len}: {err}"
+        ));
+    }
+    a.push_str(b);
+    Ok(a)
+}
+
+/// Repetition: `s.repeat(n)`.
+///
+/// Returns an error if the resulting length exceeds `isize::MAX` or if the allocation fails,
+/// instead of panicking or aborting. This makes it safe to use with untrusted repeat counts.
+/// ```
+/// use cadd::string::crepeat;
+///
+/// assert_eq!(crepeat("ab", 3).unwrap(), "ababab");
+/// assert!(crepeat("ab", usize::MAX).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn crepeat(s: &str, n: usize) -> crate::Result<String> {
+    let len = match s.len().checked_mul(n) {
+        Some(len) if len <= isize::MAX as usize => len,
+        _ => {
+            return Err(error!(
+                "repeat",
+                "string length overflow: {} * {}",
+                s.len(),
+                n
+            ))
+        }
+    };
+    let mut result = String::new();
+    if len == 0 {
+        // Avoid looping `n` times when `s` is empty.
+        return Ok(result);
+    }
+    if let Err(err) = result.try_reserve_exact(len) {
+        return Err(error!(
+            "repeat",
+            "cannot allocate string of length {len}: {err}"
+        ));
+    }
+    for _ in 0..n {
+        result.push_str(s);
+    }
+    Ok(result)
+}
+
+/// Creates an empty string with space for at least `capacity` bytes.
+///
+/// Unlike [`String::with_capacity`], returns an error instead of panicking or aborting
+/// if `capacity` exceeds `isize::MAX` or if the allocation fails.
+/// ```
+/// use cadd::string::cwith_capacity;
+///
+/// assert!(cwith_capacity(16).unwrap().capacity() >= 16);
+/// assert!(cwith_capacity(usize::MAX).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn cwith_capacity(capacity: usize) -> crate::Result<String> {
+    if capacity > isize::MAX as usize {
+        return Err(error!(
+            "with_capacity",
+            "capacity overflow: {} bytes", capacity
+        ));
+    }
+    let mut s = String::new();
+    if let Err(err) = s.try_reserve_exact(capacity) {
+        return Err(error!(
+            "with_capacity",
+            "cannot allocate {} bytes: {}", capacity, err
+        ));
+    }
+    Ok(s)
+}
@@ -56,3 +56,1976 @@ fn test1() {
     assert_err(0u32.to_non_zero(), "unexpected zero value");
     assert_err(non_zero(0u32), "unexpected zero value");
 }
+
+#[test]
+fn error_category() {
+    assert_eq!(200u8.cadd(100u8).unwrap_err().category(), "add");
+    assert_eq!(1u8.cdiv(0u8).unwrap_err().category(), "div");
+    assert_eq!(1u8.cdiv_euclid(0u8).unwrap_err().category(), "div_euclid");
+    assert_eq!(
+        (-5i32).cinto_type::<u32>().unwrap_err().category(),
+        "convert"
+    );
+    assert_eq!(non_zero(0u32).unwrap_err().category(), "convert");
+    assert_eq!(crate::Error::new("x".into()).category(), "other");
+}
+
+#[test]
+fn duration_from_secs_nanos() {
+    use core::time::Duration;
+
+    assert_eq!(
+        Duration::cfrom((5, 1_500_000_000)).unwrap(),
+        Duration::new(6, 500_000_000)
+    );
+    assert_eq!(
+        Duration::cfrom((u64::MAX, 999_999_999)).unwrap(),
+        Duration::MAX
+    );
+    assert_err(
+        Duration::cfrom((u64::MAX, 1_000_000_000)),
+        "cannot convert (18446744073709551615, 1000000000) to Duration: value is out of bounds",
+    );
+    assert_eq!(
+        <(u64, u32)>::cfrom(Duration::new(6, 500)).unwrap(),
+        (6, 500)
+    );
+}
+
+#[test]
+fn time_distance() {
+    use {
+        core::time::Duration,
+        std::time::{Instant, SystemTime},
+    };
+
+    let a = Instant::now();
+    let b = a + Duration::from_secs(5);
+    assert_eq!(a.distance(b).unwrap(), Duration::from_secs(5));
+    assert_eq!(b.distance(a).unwrap(), Duration::from_secs(5));
+
+    let a = SystemTime::now();
+    let b = a + Duration::from_secs(5);
+    assert_eq!(distance(a, b).unwrap(), Duration::from_secs(5));
+    assert_eq!(distance(b, a).unwrap(), Duration::from_secs(5));
+}
+
+#[test]
+fn encode_utf8() {
+    let mut buf = [0; 1];
+    assert_err(
+        '€'.cencode_utf8(&mut buf),
+        "buffer is too small to encode '€' as UTF-8: required 3 bytes, available 1",
+    );
+    assert_eq!('a'.cencode_utf8(&mut buf).unwrap(), "a");
+}
+
+#[test]
+fn float_pow() {
+    assert_eq!(2.0f64.cpow(3).unwrap(), 8.0);
+    assert_eq!(4.0f32.cpow(0.5f32).unwrap(), 2.0);
+    assert_err(
+        (-1.0f64).cpow(0.5f64),
+        "result is not finite: pow(-1.0, 0.5)",
+    );
+    assert_err(10.0f32.cpow(100), "result is not finite: pow(10.0, 100)");
+}
+
+#[test]
+fn parse_canonical() {
+    use crate::parse::parse_canonical;
+
+    assert_eq!(parse_canonical::<i32>("-15").unwrap(), -15);
+    assert_eq!(parse_canonical::<u8>("0").unwrap(), 0);
+    assert_err(
+        parse_canonical::<i32>("+5"),
+        "non-canonical literal \"+5\" (canonical form is \"5\")",
+    );
+    assert_err(
+        parse_canonical::<u8>("300"),
+        "cannot parse \"300\" as u8: number too large to fit in target type",
+    );
+    assert_eq!(parse_canonical::<f64>("1.5").unwrap(), 1.5);
+    assert_err(
+        parse_canonical::<f64>("1.0"),
+        "non-canonical literal \"1.0\" (canonical form is \"1\")",
+    );
+}
+
+#[test]
+fn non_zero_usize() {
+    use core::num::NonZero;
+
+    let capacity = NonZero::<usize>::cfrom(4usize).unwrap();
+    assert_err(NonZero::<usize>::cfrom(0usize), "cannot convert value 0 from usize to core::num::nonzero::NonZero<usize>: value is out of bounds");
+    assert_eq!(capacity.cadd(1usize).unwrap().get(), 5);
+    assert_eq!(capacity.cmul(capacity).unwrap().get(), 16);
+    assert_eq!(capacity.cpow(3).unwrap().get(), 64);
+    assert_eq!(capacity.cnext_power_of_two().unwrap().get(), 4);
+    assert_err(
+        capacity.cadd(usize::MAX),
+        &format!("overflow: 4 + {}", usize::MAX),
+    );
+    assert_err(capacity.cpow(64), "overflow: pow(4, 64)");
+}
+
+#[test]
+fn div_round() {
+    use RoundingMode::*;
+
+    #[track_caller]
+    fn check(a: i32, b: i32, expected: [i32; 6]) {
+        let actual = [
+            TowardZero,
+            AwayFromZero,
+            Floor,
+            Ceil,
+            HalfAwayFromZero,
+            HalfEven,
+        ]
+        .map(|mode| a.cdiv_round(b, mode).unwrap());
+        assert_eq!(actual, expected, "{a} / {b}");
+    }
+
+    // [TowardZero, AwayFromZero, Floor, Ceil, HalfAwayFromZero, HalfEven]
+    check(6, 3, [2, 2, 2, 2, 2, 2]);
+    check(-6, 3, [-2, -2, -2, -2, -2, -2]);
+    check(7, 2, [3, 4, 3, 4, 4, 4]);
+    check(-7, 2, [-3, -4, -4, -3, -4, -4]);
+    check(7, -2, [-3, -4, -4, -3, -4, -4]);
+    check(-7, -2, [3, 4, 3, 4, 4, 4]);
+    check(5, 2, [2, 3, 2, 3, 3, 2]);
+    check(-5, 2, [-2, -3, -3, -2, -3, -2]);
+    check(-5, -2, [2, 3, 2, 3, 3, 2]);
+    check(-7, 3, [-2, -3, -3, -2, -2, -2]);
+    check(-8, 3, [-2, -3, -3, -2, -3, -3]);
+    check(i32::MIN, 2, [i32::MIN / 2; 6]);
+    check(
+        i32::MAX,
+        2,
+        [
+            i32::MAX / 2,
+            i32::MAX / 2 + 1,
+            i32::MAX / 2,
+            i32::MAX / 2 + 1,
+            i32::MAX / 2 + 1,
+            i32::MAX / 2 + 1,
+        ],
+    );
+
+    assert_eq!(255u8.cdiv_round(2, HalfEven).unwrap(), 128);
+    assert_eq!(254u8.cdiv_round(4, HalfEven).unwrap(), 64);
+    assert_eq!(254u8.cdiv_round(4, Floor).unwrap(), 63);
+    assert_err(5u8.cdiv_round(0, Floor), "division by zero: 5 / 0");
+    assert_err(i8::MIN.cdiv_round(-1, Ceil), "overflow: -128 / -1");
+}
+
+#[cfg(feature = "duration-parse")]
+#[test]
+fn duration_parse() {
+    use core::time::Duration;
+
+    assert_eq!(
+        Duration::cfrom("1h30m10s").unwrap(),
+        Duration::from_secs(5410)
+    );
+    assert_eq!(
+        Duration::cfrom("2s500ms").unwrap(),
+        Duration::from_millis(2500)
+    );
+    assert_eq!(Duration::cfrom("1d").unwrap(), Duration::from_secs(86_400));
+    assert_err(Duration::cfrom(""), "invalid duration \"\": empty string");
+    assert_err(
+        Duration::cfrom("1h30"),
+        "invalid duration \"1h30\": expected a unit at position 4, got \"\"",
+    );
+    assert_err(
+        Duration::cfrom("1h 30m"),
+        "invalid duration \"1h 30m\": expected a number at position 2",
+    );
+    assert_err(
+        Duration::cfrom("30m1h"),
+        "invalid duration \"30m1h\": unit \"h\" at position 4 is out of order",
+    );
+    assert_err(
+        Duration::cfrom("1h1h"),
+        "invalid duration \"1h1h\": unit \"h\" at position 3 is out of order",
+    );
+    assert_err(
+        Duration::cfrom("5x"),
+        "invalid duration \"5x\": expected a unit at position 1, got \"x\"",
+    );
+    assert_err(
+        Duration::cfrom("18446744073709551615d"),
+        "invalid duration \"18446744073709551615d\": overflow at position 0",
+    );
+}
+
+#[test]
+fn backtrace_string() {
+    let err = 200u8.cadd(100u8).unwrap_err();
+    assert_eq!(err.backtrace_string().is_some(), backtrace_enabled());
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn ratio_from_f64() {
+    use num_rational::Ratio;
+
+    assert_eq!(Ratio::<i64>::cfrom(0.0).unwrap(), Ratio::new(0, 1));
+    assert_eq!(Ratio::<i64>::cfrom(-2.5).unwrap(), Ratio::new(-5, 2));
+    assert_eq!(
+        Ratio::<i64>::cfrom(1e18).unwrap(),
+        Ratio::new(1e18 as i64, 1)
+    );
+    let tenth = Ratio::<i64>::cfrom(0.1).unwrap();
+    assert!((*tenth.numer() as f64 / *tenth.denom() as f64 - 0.1).abs() < 1e-17);
+    assert_err(
+        Ratio::<i64>::cfrom(f64::INFINITY),
+        "cannot convert inf to a ratio: value is not finite",
+    );
+    assert_err(
+        Ratio::<i64>::cfrom(1e19),
+        "cannot convert 1e19 to Ratio<i64>: value is out of bounds",
+    );
+    assert_err(
+        crate::convert::ratio_from_f64(0.5, 0),
+        "cannot convert 0.5 to a ratio: max denominator 0 is less than 1",
+    );
+}
+
+#[test]
+fn lossless_shift() {
+    assert_eq!(0b0011u8.cshl_lossless(6).unwrap(), 0b1100_0000);
+    assert_err(0b0011u8.cshl_lossless(7), "shift loses bits: 3 << 7");
+    assert_err(1u8.cshl_lossless(8), "shift amount is too large: 1 << 8");
+    assert_eq!((-1i8).cshl_lossless(7).unwrap(), i8::MIN);
+    assert_err(1i8.cshl_lossless(7), "shift loses bits: 1 << 7");
+    assert_err((-65i8).cshl_lossless(1), "shift loses bits: -65 << 1");
+
+    assert_eq!(0b1100u8.cshr_lossless(2).unwrap(), 0b11);
+    assert_err(0b1100u8.cshr_lossless(3), "shift loses bits: 12 >> 3");
+    assert_eq!((-4i32).cshr_lossless(2).unwrap(), -1);
+    assert_err((-3i32).cshr_lossless(1), "shift loses bits: -3 >> 1");
+    assert_err(0u64.cshr_lossless(64), "shift amount is too large: 0 >> 64");
+    assert_eq!(0u64.cshr_lossless(63).unwrap(), 0);
+}
+
+#[test]
+fn error_location() {
+    let line = line!() + 1;
+    let err = 200u8.cadd(100u8).unwrap_err();
+    assert_eq!(
+        (err.location().file(), err.location().line()),
+        (file!(), line)
+    );
+    let line = line!() + 1;
+    let err = cmul(200u8, 2u8).unwrap_err();
+    assert_eq!(err.location().line(), line);
+    let line = line!() + 1;
+    let err = (-5i32).cinto_type::<u32>().unwrap_err();
+    assert_eq!(err.location().line(), line);
+    let line = line!() + 1;
+    let err = u8::cfrom(300u32).unwrap_err();
+    assert_eq!(err.location().line(), line);
+    let line = line!() + 1;
+    let err = 0u32.to_non_zero().unwrap_err();
+    assert_eq!(err.location().line(), line);
+}
+
+#[test]
+fn error_location_helpers() {
+    use crate::layout::{array_layout, layout_size};
+
+    let line = line!() + 1;
+    let err = layout_size(usize::MAX, 2).unwrap_err();
+    assert_eq!(
+        (err.location().file(), err.location().line()),
+        (file!(), line)
+    );
+    let line = line!() + 1;
+    let err = array_layout(1, 8, 3).unwrap_err();
+    assert_eq!(err.location().line(), line);
+
+    #[cfg(feature = "duration-parse")]
+    {
+        use core::time::Duration;
+
+        let line = line!() + 1;
+        let err = Duration::cfrom("5x").unwrap_err();
+        assert_eq!(err.location().line(), line);
+    }
+    #[cfg(feature = "num-rational")]
+    {
+        use num_rational::Ratio;
+
+        let line = line!() + 1;
+        let err = Ratio::<i64>::cfrom(f64::NAN).unwrap_err();
+        assert_eq!(err.location().line(), line);
+        let line = line!() + 1;
+        let err = crate::convert::ratio_from_f64(0.5, 0).unwrap_err();
+        assert_eq!(err.location().line(), line);
+    }
+}
+
+#[test]
+fn would_overflow() {
+    assert!(u8::MAX.would_overflow_add(1));
+    assert!(!254u8.would_overflow_add(1));
+    assert!(0u32.would_overflow_sub(1));
+    assert!(i32::MIN.would_overflow_sub(1));
+    assert!(!(-5i32).would_overflow_sub(1));
+    assert!(3u64.would_overflow_pow(41));
+    assert!(!3u64.would_overflow_pow(40));
+    assert!(1u8.would_overflow_neg());
+    assert!(!0u8.would_overflow_neg());
+}
+
+#[test]
+fn ffi_aliases() {
+    use core::ffi::{c_char, c_int, c_long, c_uint, c_ulong};
+
+    // These compile on every platform regardless of the actual alias types.
+    assert_eq!(c_int::cfrom(5i32).unwrap(), 5);
+    assert_eq!(i64::cfrom(5 as c_long).unwrap(), 5);
+    assert_eq!(c_uint::cfrom(5u64).unwrap(), 5);
+    assert_eq!(u64::cfrom(5 as c_ulong).unwrap(), 5);
+    assert_eq!(u8::cfrom(b'a' as c_char).unwrap(), b'a');
+    assert_eq!(c_int::saturating_from(i64::MAX), c_int::MAX);
+    assert_err(
+        c_uint::cfrom(-1 as c_int),
+        &format!(
+            "cannot convert value -1 from {} to {}: value is out of bounds",
+            core::any::type_name::<c_int>(),
+            core::any::type_name::<c_uint>(),
+        ),
+    );
+
+    // `long` is 64-bit on 64-bit Unix (LP64) and 32-bit on Windows (LLP64).
+    if cfg!(all(unix, target_pointer_width = "64")) {
+        assert_eq!(
+            i64::cfrom(c_long::cfrom(i64::MAX).unwrap()).unwrap(),
+            i64::MAX
+        );
+    } else if cfg!(windows) {
+        assert_err(
+            c_long::cfrom(i64::MAX),
+            "cannot convert value 9223372036854775807 from i64 to i32: value is out of bounds",
+        );
+    }
+}
+
+#[test]
+fn sign_magnitude() {
+    use crate::bytes::{
+        from_sign_magnitude_bytes, from_sign_magnitude_bytes_strict, to_sign_magnitude_bytes,
+        Endian,
+    };
+
+    for value in [0i32, 1, -1, 1000, -1000, i32::MAX, -i32::MAX] {
+        for endian in [Endian::Big, Endian::Little] {
+            let bytes = to_sign_magnitude_bytes(value, endian).unwrap();
+            assert_eq!(
+                from_sign_magnitude_bytes_strict::<i32>(&bytes, endian).unwrap(),
+                value
+            );
+        }
+    }
+    assert_eq!(
+        to_sign_magnitude_bytes(-1i32, Endian::Little).unwrap(),
+        [1, 0, 0, 0x80]
+    );
+    assert_eq!(
+        from_sign_magnitude_bytes::<i8>(&[0xFF], Endian::Big).unwrap(),
+        -127
+    );
+    assert_err(
+        from_sign_magnitude_bytes::<i32>(&[1, 2, 3], Endian::Big),
+        "cannot decode i32 from sign-magnitude bytes: expected 4 bytes, got 3",
+    );
+    assert_err(
+        from_sign_magnitude_bytes_strict::<i8>(&[0x80], Endian::Big),
+        "cannot decode i8 from sign-magnitude bytes: negative zero is not allowed: [128]",
+    );
+    assert_err(
+        to_sign_magnitude_bytes(i8::MIN, Endian::Big),
+        "cannot encode -128 as sign-magnitude: value is out of bounds",
+    );
+}
+
+#[test]
+fn duration_units() {
+    use {
+        crate::time::{DurationUnit, DurationUnits},
+        core::time::Duration,
+    };
+
+    for unit in [
+        DurationUnit::Nanos,
+        DurationUnit::Micros,
+        DurationUnit::Millis,
+        DurationUnit::Secs,
+    ] {
+        let max = Duration::MAX.to_units(unit);
+        let duration = Duration::cfrom_units(max, unit).unwrap();
+        assert_eq!(duration.to_units(unit), max);
+        assert!(Duration::MAX - duration < Duration::from_secs(1));
+        assert!(Duration::cfrom_units(max + 1, unit).is_err());
+    }
+    assert_err(
+        Duration::cfrom_units(18_446_744_073_709_551_616_000, DurationUnit::Millis),
+        "cannot convert 18446744073709551616000 milliseconds to Duration: value is out of bounds",
+    );
+    assert_eq!(
+        Duration::cfrom_units(1_234_567, DurationUnit::Micros).unwrap(),
+        Duration::new(1, 234_567_000)
+    );
+}
+
+#[test]
+fn newtype_ops() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Count(u32);
+
+    crate::impl_checked_ops!(Count => u32);
+
+    assert_eq!(cadd(Count(2), Count(3)).unwrap(), Count(5));
+    assert_eq!(Count(7).crem(Count(4)).unwrap(), Count(3));
+    assert_err(Count(2).csub(Count(3)), "Count: overflow: 2 - 3");
+    assert_err(Count(1).cneg(), "Count: overflow: -1");
+    assert_eq!(Count(0).cneg().unwrap(), Count(0));
+}
+
+#[test]
+fn digest_words() {
+    use crate::bytes::{
+        u128_from_bytes_be, u128_from_bytes_le, u128_from_slice_be, u256_words_from_bytes_be,
+        u256_words_from_slice_be,
+    };
+
+    let bytes: [u8; 16] = core::array::from_fn(|i| i as u8);
+    assert_eq!(
+        u128_from_bytes_be(&bytes),
+        0x000102030405060708090a0b0c0d0e0f
+    );
+    assert_eq!(
+        u128_from_bytes_le(&bytes),
+        0x0f0e0d0c0b0a09080706050403020100
+    );
+    assert_err(
+        u128_from_slice_be(&bytes[1..]),
+        "cannot decode a 128-bit value from bytes: expected 16 bytes, got 15",
+    );
+
+    let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let words = u256_words_from_bytes_be(&bytes);
+    assert_eq!(words[0], 0x000102030405060708090a0b0c0d0e0f);
+    assert_eq!(words[1], 0x101112131415161718191a1b1c1d1e1f);
+    assert_err(
+        u256_words_from_slice_be(&[0; 33]),
+        "cannot decode a 256-bit value from bytes: expected 32 bytes, got 33",
+    );
+}
+
+#[test]
+fn retryable() {
+    let err = crate::Error::tagged("io", "timed out".into());
+    assert!(!err.is_retryable());
+    let err = err.retryable(true);
+    assert!(err.is_retryable());
+    assert_eq!(err.category(), "io");
+    assert!(!err.retryable(false).is_retryable());
+}
+
+#[test]
+fn reinterpret() {
+    assert_eq!(u8::reinterpret_from(i8::MIN), 128);
+    assert_eq!(i128::reinterpret_from(u128::MAX), -1);
+    assert_eq!(
+        isize::MIN.reinterpret_into_type::<usize>(),
+        1 << (usize::BITS - 1)
+    );
+    let value: u64 = (-2_i64).reinterpret_into();
+    assert_eq!(value.reinterpret_into_type::<i64>(), -2);
+}
+
+#[test]
+fn ilog_non_zero_base() {
+    let base = non_zero(10u32).unwrap();
+    assert_eq!(1000u32.cilog(base).unwrap(), 3);
+    assert_err(0u32.cilog(base), "number is not positive: ilog(0, 10)");
+    assert_err(
+        8i32.cilog(non_zero(1i32).unwrap()),
+        "base is less than 2: ilog(8, 1)",
+    );
+}
+
+#[test]
+fn array_padded() {
+    use crate::convert::array_from_vec_padded;
+
+    let empty: [alloc::string::String; 2] = array_from_vec_padded(alloc::vec![]).unwrap();
+    assert_eq!(empty, ["", ""]);
+    assert_eq!(
+        array_from_vec_padded::<i32, 3>(alloc::vec![-1]).unwrap(),
+        [-1, 0, 0]
+    );
+    assert_err(
+        array_from_vec_padded::<i32, 1>(alloc::vec![1, 2]),
+        "expected at most 1 elements, got 2",
+    );
+}
+
+#[test]
+fn sub_or_zero() {
+    assert_eq!(1u8.csub_or_zero(2), 0);
+    assert_eq!(u128::MAX.csub_or_zero(1), u128::MAX - 1);
+    assert_eq!(3usize.csub_or_zero(3), 0);
+}
+
+#[test]
+fn wrapping_saturating() {
+    use core::num::{Saturating, Wrapping};
+
+    assert_eq!(
+        Saturating::<i64>::cfrom(Wrapping(-5)).unwrap(),
+        Saturating(-5)
+    );
+    let value: Wrapping<u16> = Saturating(u16::MAX).cinto().unwrap();
+    assert_eq!(value + Wrapping(1), Wrapping(0));
+}
+
+#[test]
+fn pow_widening() {
+    assert_eq!(255u8.cpow_widening(2).unwrap(), 65025u16);
+    assert_eq!((-2i64).cpow_widening(127).unwrap(), i128::MIN);
+    assert_eq!(
+        cpow_widening(u64::MAX, 2).unwrap(),
+        u64::MAX as u128 * u64::MAX as u128
+    );
+    assert_err(2i32.cpow_widening(63), "overflow: pow(2, 63) as i64");
+}
+
+#[test]
+fn message_formatter() {
+    // Use a dedicated category because the formatter table is global.
+    fn formatter(err: &crate::Error, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "localized: {}", err.message())
+    }
+
+    let err = crate::Error::tagged("test_formatter", "original".into());
+    assert_err(Err::<(), _>(err), "original");
+    crate::set_message_formatter("test_formatter", formatter);
+    let err = crate::Error::tagged("test_formatter", "original".into());
+    assert_eq!(err.message(), "original");
+    assert_err(Err::<(), _>(err), "localized: original");
+}
+
+#[test]
+fn duration_offset_nanos() {
+    use core::time::Duration;
+
+    assert_eq!(
+        Duration::from_nanos(5).coffset_nanos(-5).unwrap(),
+        Duration::ZERO
+    );
+    assert_err(
+        Duration::from_nanos(5).coffset_nanos(-6),
+        "result is negative: offset_nanos(5ns, -6)",
+    );
+    assert_eq!(
+        Duration::ZERO
+            .coffset_nanos(i64::MIN + 1)
+            .unwrap_err()
+            .category(),
+        "offset_nanos"
+    );
+    let almost_max = Duration::MAX - Duration::from_nanos(1);
+    assert_eq!(almost_max.coffset_nanos(1).unwrap(), Duration::MAX);
+    assert!(Duration::MAX.coffset_nanos(1).is_err());
+    assert_eq!(
+        Duration::MAX.coffset_nanos(i64::MIN).unwrap(),
+        Duration::MAX - Duration::from_nanos(1 << 63)
+    );
+}
+
+#[test]
+fn repeat() {
+    use crate::string::crepeat;
+
+    assert_eq!(crepeat("", usize::MAX).unwrap(), "");
+    assert_eq!(crepeat("abc", 0).unwrap(), "");
+    assert_err(
+        crepeat("abc", usize::MAX / 2),
+        &format!("string length overflow: 3 * {}", usize::MAX / 2),
+    );
+    let err = crepeat("ab", isize::MAX as usize / 2 + 1).unwrap_err();
+    assert_eq!(err.category(), "repeat");
+}
+
+#[test]
+fn ip_addr_bytes() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let v4 = IpAddr::cfrom(&[127, 0, 0, 1][..]).unwrap();
+    assert_eq!(v4, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    assert_eq!(
+        v4.cinto_type::<alloc::vec::Vec<u8>>().unwrap(),
+        [127, 0, 0, 1]
+    );
+
+    let mut bytes = [0; 16];
+    bytes[15] = 1;
+    let v6 = IpAddr::cfrom(&bytes[..]).unwrap();
+    assert_eq!(v6, IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert_eq!(v6.cinto_type::<alloc::vec::Vec<u8>>().unwrap(), bytes);
+
+    assert_err(
+        IpAddr::cfrom(&bytes[..5]),
+        "cannot convert bytes to IpAddr: expected 4 or 16 bytes, got 5",
+    );
+}
+
+#[test]
+fn int128_to_float() {
+    assert_eq!(f32::saturating_from(u128::MAX), f32::MAX);
+    assert_eq!(f64::saturating_from(u128::MAX), u128::MAX as f64);
+    assert_eq!(f64::saturating_from(i128::MIN), -(2f64.powi(127)));
+
+    assert_eq!(f64::cfrom(1u128 << 100).unwrap(), 2f64.powi(100));
+    assert_eq!(f64::cfrom(i128::MIN).unwrap(), -(2f64.powi(127)));
+    assert_eq!(f32::cfrom(-3i128).unwrap(), -3.0);
+    assert_err(
+        f32::cfrom(u128::MAX),
+        "cannot convert value 340282366920938463463374607431768211455 from u128 to f32: \
+         value is out of bounds",
+    );
+    assert_err(
+        f64::cfrom(u128::MAX),
+        "cannot convert value 340282366920938463463374607431768211455 from u128 to f64: \
+         value cannot be represented exactly",
+    );
+    assert_err(
+        f64::cfrom((1i128 << 60) + 1),
+        "cannot convert value 1152921504606846977 from i128 to f64: \
+         value cannot be represented exactly",
+    );
+}
+
+#[test]
+fn div_pow2() {
+    for value in [
+        i16::MIN,
+        i16::MIN + 1,
+        -1000,
+        -9,
+        -8,
+        -7,
+        -1,
+        0,
+        1,
+        7,
+        8,
+        9,
+        1000,
+        i16::MAX,
+    ] {
+        for shift in 0..15 {
+            assert_eq!(
+                value.cdiv_pow2(shift).unwrap(),
+                value.cdiv(1 << shift).unwrap()
+            );
+        }
+        assert_eq!(
+            value.cdiv_pow2(15).unwrap(),
+            i32::from(value).cdiv(1 << 15).unwrap() as i16
+        );
+    }
+    for shift in 0..64 {
+        assert_eq!(u64::MAX.cdiv_pow2(shift).unwrap(), u64::MAX / (1 << shift));
+    }
+    assert_err(
+        cdiv_pow2(5i64, 64),
+        "shift amount is too large: div_pow2(5, 64)",
+    );
+}
+
+#[test]
+fn error_json() {
+    let err = crate::Error::tagged("test", "a \"quoted\" \\ value\n\u{1}".into());
+    let location = err.location();
+    assert_eq!(
+        err.json().to_string(),
+        format!(
+            r#"{{"message":"a \"quoted\" \\ value\n\u0001","category":"test","location":"{}","line":{},"column":{}}}"#,
+            location.file(),
+            location.line(),
+            location.column(),
+        )
+    );
+}
+
+#[test]
+fn utf8_strip_bom() {
+    use crate::convert::from_utf8_strip_bom;
+
+    assert_eq!(from_utf8_strip_bom(b"\xEF\xBB\xBF").unwrap(), "");
+    // Only one BOM is stripped.
+    assert_eq!(
+        from_utf8_strip_bom(b"\xEF\xBB\xBF\xEF\xBB\xBFa").unwrap(),
+        "\u{FEFF}a"
+    );
+    assert_eq!(from_utf8_strip_bom("€".as_bytes()).unwrap(), "€");
+    assert_err(
+        from_utf8_strip_bom(b"\xEF\xBB\xBFa\xFF"),
+        "not a utf-8 string: invalid utf-8 sequence of 1 bytes from index 1",
+    );
+    assert_err(
+        from_utf8_strip_bom(b"\xEF\xBB"),
+        "not a utf-8 string: incomplete utf-8 byte sequence from index 0",
+    );
+}
+
+#[test]
+fn add_mul_all() {
+    use crate::accumulate::{cadd_all, cmul_all};
+
+    assert_eq!(cadd_all::<i8>(&[]).unwrap(), 0);
+    assert_eq!(cmul_all::<i8>(&[]).unwrap(), 1);
+    assert_eq!(cadd_all(&[i64::MAX, -1, 1]).unwrap(), i64::MAX);
+    assert_eq!(cmul_all(&[-2i128, 3, -4]).unwrap(), 24);
+    assert_err(
+        cadd_all(&[-100i8, -28, -1]),
+        "overflow adding [-100, -28, -1]: running sum -128 + -1 at index 2",
+    );
+    let values: alloc::vec::Vec<u32> = (1..=40).collect();
+    assert_err(
+        cmul_all(&values),
+        "overflow multiplying [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, \"...\", \
+         25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40]: \
+         running product 479001600 * 13 at index 12",
+    );
+}
+
+#[test]
+fn saturating_abs_non_zero() {
+    use core::num::NonZero;
+
+    let min = NonZero::<i32>::MIN;
+    assert!(min.cabs().is_err());
+    assert_eq!(SaturatingAbs::saturating_abs(min), NonZero::<i32>::MAX);
+    assert_eq!(
+        SaturatingAbs::saturating_abs(non_zero(-7i32).unwrap()).get(),
+        7
+    );
+    assert_eq!(SaturatingAbs::saturating_abs(i8::MIN), i8::MAX);
+}
+
+#[test]
+fn parse_prefixed() {
+    use crate::parse::{cparse_prefixed, cparse_radix};
+
+    assert_eq!(cparse_prefixed::<u8>("0XfF").unwrap(), 255);
+    assert_eq!(cparse_prefixed::<i64>("+0O777").unwrap(), 0o777);
+    assert_eq!(
+        cparse_prefixed::<i128>("-0x80000000000000000000000000000000").unwrap(),
+        i128::MIN
+    );
+    assert_eq!(cparse_prefixed::<u32>("0").unwrap(), 0);
+    assert_eq!(cparse_prefixed::<i8>("-0").unwrap(), 0);
+    assert_err(
+        cparse_prefixed::<u32>("0x"),
+        "cannot parse \"0x\" as u32 (base 16): no digits",
+    );
+    assert_err(
+        cparse_prefixed::<u32>(""),
+        "cannot parse \"\" as u32 (base 10): no digits",
+    );
+    assert_err(
+        cparse_prefixed::<u32>("0x0x10"),
+        "cannot parse \"0x0x10\" as u32 (base 16): invalid digit found in string",
+    );
+    assert_err(
+        cparse_prefixed::<i32>("0x-5"),
+        "cannot parse \"0x-5\" as i32 (base 16): invalid digit found in string",
+    );
+    assert_err(
+        cparse_prefixed::<u8>("-0b1"),
+        "cannot parse \"-0b1\" as u8 (base 2): number is out of bounds",
+    );
+    assert_err(
+        cparse_prefixed::<i128>("-0x80000000000000000000000000000001"),
+        "cannot parse \"-0x80000000000000000000000000000001\" as i128 (base 16): \
+         number is out of bounds",
+    );
+    assert_err(
+        cparse_radix::<u32>("10", 37),
+        "cannot parse \"10\" as u32 (base 37): base must be from 2 to 36",
+    );
+    assert_eq!(cparse_radix::<u16>("zz", 36).unwrap(), 35 * 36 + 35);
+    // Longer than `u128::MAX` (39 digits).
+    assert_err(
+        cparse_radix::<u64>("1234567890123456789012345678901234567890", 10),
+        "cannot parse \"1234567890123456789012345678901234567890\" as u64 (base 10): \
+         number is out of bounds",
+    );
+    assert_err(
+        cparse_prefixed::<i128>("-1234567890123456789012345678901234567890"),
+        "cannot parse \"-1234567890123456789012345678901234567890\" as i128 (base 10): \
+         number is out of bounds",
+    );
+}
+
+#[test]
+fn range_shift() {
+    use crate::range::{shift_range, shift_range_signed};
+
+    assert_eq!(shift_range(0..usize::MAX - 1, 1).unwrap(), 1..usize::MAX);
+    assert_err(
+        shift_range(0..usize::MAX, 1),
+        &format!("overflow: shift_range(0..{}, 1)", usize::MAX),
+    );
+    assert_eq!(shift_range_signed(5..5, -5).unwrap(), 0..0);
+    assert_err(
+        shift_range_signed(0..10, -1),
+        "overflow: shift_range(0..10, -1)",
+    );
+    assert!(shift_range_signed(usize::MAX - 1..usize::MAX, isize::MAX).is_err());
+}
+
+#[test]
+fn chars_to_string() {
+    use alloc::string::String;
+
+    let chars = ['a', 'é', '€', '🦀'];
+    let value = String::cfrom(&chars[..]).unwrap();
+    assert_eq!(value, "aé€🦀");
+    assert_eq!(value.capacity(), 10);
+    assert_eq!(String::cfrom(&[][..]).unwrap(), "");
+}
+
+#[test]
+fn chars_to_string_length_overflow() {
+    use crate::convert_impls::string::total_len;
+
+    assert_eq!(total_len([1, 4, 2]), Some(7));
+    assert_eq!(total_len([isize::MAX as usize]), Some(isize::MAX as usize));
+    assert_eq!(total_len([isize::MAX as usize, 1]), None);
+    assert_eq!(total_len([usize::MAX, 1]), None);
+}
+
+#[test]
+fn public_assert_err() {
+    let err = 5u8.csub(6u8).unwrap_err();
+    assert!(err.matches_message("overflow: 5 - 6"));
+    assert!(!err.matches_message("overflow"));
+    crate::assert_cadd_err!(5u8.csub(6u8), "overflow: 5 - 6");
+    crate::assert_cadd_err!(
+        (-1i32).cinto_type::<u8>(),
+        &format!(
+            "cannot convert value {} from i32 to u8: value is out of bounds",
+            -1
+        )
+    );
+}
+
+#[test]
+fn duration_ticks() {
+    use {
+        crate::time::{duration_from_ticks, duration_to_ticks},
+        core::{num::NonZero, time::Duration},
+    };
+
+    let max_hz = NonZero::new(u32::MAX).unwrap();
+    let one_hz = NonZero::new(1).unwrap();
+    assert_eq!(
+        duration_from_ticks(u64::MAX, one_hz),
+        Duration::from_secs(u64::MAX)
+    );
+    assert_eq!(
+        duration_from_ticks(u64::MAX, max_hz).as_secs(),
+        u64::MAX / u64::from(u32::MAX)
+    );
+    assert_eq!(duration_from_ticks(1, max_hz), Duration::from_nanos(0));
+    assert_eq!(duration_to_ticks(Duration::MAX, one_hz).unwrap(), u64::MAX);
+    assert_eq!(
+        duration_to_ticks(Duration::from_secs(1), max_hz).unwrap(),
+        u64::from(u32::MAX)
+    );
+    assert_err(
+        duration_to_ticks(
+            Duration::from_secs(u64::MAX / 2 + 1),
+            NonZero::new(2).unwrap(),
+        ),
+        "cannot convert 9223372036854775808s to ticks at 2 Hz: value is out of bounds",
+    );
+    let ticks = duration_to_ticks(Duration::from_secs(1_000_000), max_hz).unwrap();
+    assert_eq!(
+        duration_from_ticks(ticks, max_hz),
+        Duration::from_secs(1_000_000)
+    );
+}
+
+#[test]
+fn mean_min_max() {
+    use crate::accumulate::{CheckedMean, CheckedMinMax};
+
+    let mut mean = CheckedMean::default();
+    mean.add(i64::MAX).unwrap();
+    assert_err(mean.add(1), "overflow: 9223372036854775807 + 1");
+    mean.add(-1).unwrap();
+    assert_eq!(mean.finish().unwrap(), (i64::MAX - 1) as f64 / 2.0);
+    assert_err(
+        CheckedMean::default().finish(),
+        "cannot compute the mean of zero samples",
+    );
+
+    let mut min_max = CheckedMinMax::default();
+    min_max.add(u8::MAX);
+    assert_eq!(min_max.finish().unwrap(), (u8::MAX, u8::MAX));
+    min_max.extend([0, 5]);
+    assert_eq!(min_max.finish().unwrap(), (0, u8::MAX));
+    assert_err(
+        CheckedMinMax::<u8>::default().finish(),
+        "cannot compute the range of zero values",
+    );
+}
+
+#[test]
+fn parse_bool() {
+    for token in ["true", "TRUE", "Yes", "on", "1"] {
+        assert!(bool::cfrom(token).unwrap(), "{token}");
+    }
+    for token in ["false", "False", "NO", "off", "0"] {
+        assert!(!bool::cfrom(token).unwrap(), "{token}");
+    }
+    assert_err(bool::cfrom("maybe"), "cannot parse \"maybe\" as bool");
+    assert_err(bool::cfrom(" true"), "cannot parse \" true\" as bool");
+    assert_err(bool::cfrom(""), "cannot parse \"\" as bool");
+}
+
+#[test]
+fn non_zero_shl() {
+    let one = non_zero(1u32).unwrap();
+    assert_eq!(one.cshl(31).unwrap().get(), 1 << 31);
+    assert_err(one.cshl(32), "shift amount is too large: 1 << 32");
+    let two = non_zero(2u32).unwrap();
+    assert_err(two.cshl(31), "result is zero: 2 << 31");
+    assert_eq!(
+        non_zero(u128::MAX).unwrap().cshl(127).unwrap().get(),
+        1 << 127
+    );
+}
+
+#[test]
+fn usize_u64() {
+    assert_eq!(u64::cfrom(usize::MAX).unwrap() as u128, usize::MAX as u128);
+    assert_eq!(
+        usize::cfrom(u64::from(u16::MAX)).unwrap(),
+        usize::from(u16::MAX)
+    );
+    if usize::BITS < u64::BITS {
+        assert_err(
+            usize::cfrom(u64::MAX),
+            "cannot convert value 18446744073709551615 from u64 to usize: value is out of bounds",
+        );
+    } else {
+        assert_eq!(usize::cfrom(u64::MAX).unwrap() as u64, u64::MAX);
+    }
+    assert_eq!(
+        usize::saturating_from(u64::MAX) as u128,
+        (usize::MAX as u128).min(u64::MAX as u128)
+    );
+}
+
+#[test]
+fn icbrt() {
+    let reference = |value: u32| {
+        (0..)
+            .take_while(|r: &u32| r.pow(3) <= value)
+            .last()
+            .unwrap()
+    };
+    for value in 0..=u16::MAX {
+        assert_eq!(value.cicbrt().unwrap(), reference(value.into()) as u16);
+    }
+    for value in 0..=u8::MAX {
+        assert_eq!(cicbrt(value).unwrap(), reference(value.into()) as u8);
+    }
+    for root in [1u128, 2, 1000, 2_642_245, 1 << 42, 6_981_463_658_331] {
+        assert_eq!((root.pow(3)).cicbrt().unwrap(), root);
+        assert_eq!((root.pow(3) - 1).cicbrt().unwrap(), root - 1);
+    }
+    assert_eq!(u128::MAX.cicbrt().unwrap(), 6_981_463_658_331);
+    assert_eq!(u32::MAX.cicbrt().unwrap(), 1625);
+}
+
+#[test]
+fn lazy_message() {
+    use core::sync::atomic::AtomicUsize;
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let err = crate::Error::from_fn(|f| {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        write!(f, "lazy {}", 42)
+    });
+    assert_eq!(CALLS.load(Ordering::Relaxed), 0);
+    assert_eq!(err.category(), "other");
+    assert_err(Err::<(), _>(err), "lazy 42");
+    assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+
+    let err = crate::Error::from_fn(|f| f.write_str("cached"));
+    assert_eq!(err.message(), "cached");
+    assert_eq!(err.message(), "cached");
+    assert!(err.to_string().starts_with("cached"));
+}
+
+#[test]
+fn from_byte_array() {
+    use crate::bytes::FromByteArray;
+
+    assert_eq!(u8::from_be_array([7]), 7);
+    assert_eq!(i64::from_le_array([0xFF; 8]), -1);
+    assert_eq!(u128::from_be_array([1; 16]), u128::from_le_array([1; 16]));
+    assert_eq!(
+        u64::from_ne_array(0x0102030405060708u64.to_ne_bytes()),
+        0x0102030405060708
+    );
+    assert_eq!(usize::from_be_array(usize::MAX.to_be_bytes()), usize::MAX);
+    assert_eq!(isize::from_le_array((-5isize).to_le_bytes()), -5);
+}
+
+#[test]
+fn vec_reserve() {
+    use crate::vec::ctry_reserve;
+
+    let mut v = alloc::vec![0u64; 3];
+    ctry_reserve(&mut v, 0).unwrap();
+    assert_err(
+        ctry_reserve(&mut v, usize::MAX - 2),
+        &format!(
+            "capacity overflow: 3 + {} elements of 8 bytes",
+            usize::MAX - 2
+        ),
+    );
+    assert_err(
+        ctry_reserve(&mut v, usize::MAX / 8),
+        &format!(
+            "capacity overflow: 3 + {} elements of 8 bytes",
+            usize::MAX / 8
+        ),
+    );
+    let mut units = alloc::vec![(); 3];
+    ctry_reserve(&mut units, usize::MAX - 3).unwrap();
+}
+
+#[test]
+fn wrapping_report() {
+    use core::num::Wrapping;
+
+    let (value, wrapped) = Wrapping(i32::MAX).cadd_report(Wrapping(1));
+    assert_eq!((value, wrapped), (Wrapping(i32::MIN), true));
+    assert_eq!(value, Wrapping(i32::MAX) + Wrapping(1));
+    assert_eq!(
+        Wrapping(-1i64).csub_report(Wrapping(1)),
+        (Wrapping(-2), false)
+    );
+    assert_eq!(
+        Wrapping(1u128 << 64).cmul_report(Wrapping(1 << 64)),
+        (Wrapping(0), true)
+    );
+}
+
+#[test]
+fn non_zero_abs() {
+    assert_err(0i64.to_non_zero_abs(), "unexpected zero value");
+    assert_eq!((-1i8).to_non_zero_abs().unwrap().get(), 1u8);
+    assert_eq!(i8::MIN.to_non_zero_abs().unwrap().get(), 128u8);
+    assert_eq!(i128::MIN.to_non_zero_abs().unwrap().get(), 1u128 << 127);
+    assert_eq!(
+        isize::MAX.to_non_zero_abs().unwrap().get(),
+        isize::MAX as usize
+    );
+}
+
+#[test]
+fn non_zero_mixed_operands() {
+    let max = non_zero(u64::MAX).unwrap();
+    assert_err(1u64.cadd(max), "overflow: 1 + 18446744073709551615");
+    assert_eq!(cadd(0u64, max).unwrap(), max);
+    assert_err(
+        2u16.cmul(non_zero(u16::MAX).unwrap()),
+        "overflow: 2 * 65535",
+    );
+    assert_eq!(non_zero(3usize).unwrap().cmul(4usize).unwrap(), 12);
+}
+
+#[cfg(feature = "source-value")]
+#[test]
+fn source_value() {
+    use std::{ffi::CString, string::String, vec, vec::Vec};
+
+    let bytes = CString::new(vec![0xc0, b'x']).unwrap();
+    let mut err = String::cfrom(bytes.clone()).unwrap_err();
+    assert_eq!(err.take_source_value::<Vec<u8>>(), None);
+    assert_eq!(err.take_source_value::<CString>(), Some(bytes));
+    assert!(err.message().starts_with("not a utf-8 string: "));
+}
+
+#[test]
+fn duration_sum_near_max() {
+    use {
+        crate::time::{duration_sum, duration_sum_saturating},
+        core::time::Duration,
+    };
+
+    let half = Duration::MAX / 2;
+    let nano = Duration::from_nanos(1);
+    assert_eq!(duration_sum([half, half, nano]).unwrap(), Duration::MAX);
+    assert_err(
+        duration_sum([half, half, nano, nano]),
+        "overflow adding durations: running sum 18446744073709551615.999999999s + 1ns at index 3",
+    );
+    assert_eq!(
+        duration_sum_saturating([half, half, nano, nano]),
+        Duration::MAX
+    );
+    assert_eq!(duration_sum_saturating([]), Duration::ZERO);
+}
+
+#[test]
+fn expect_into_type_panic_message() {
+    use {crate::convert::IntoType, std::string::String};
+
+    assert_eq!(7u64.expect_into_type::<u8>("small value"), 7);
+    let payload =
+        std::panic::catch_unwind(|| (-5i32).expect_into_type::<u32>("count")).unwrap_err();
+    let message = payload.downcast::<String>().unwrap();
+    assert!(message
+        .starts_with("count: cannot convert value -5 from i32 to u32: value is out of bounds"));
+}
+
+#[test]
+fn map_values_overflow() {
+    use {crate::convert::map_values_cinto, std::collections::BTreeMap};
+
+    let map = BTreeMap::from([(1u8, -1i64), (2, 5), (3, i64::MAX)]);
+    assert_err(
+        map_values_cinto::<_, _, i32>(map),
+        "cannot convert value for key 3: cannot convert value 9223372036854775807 from i64 to i32: value is out of bounds",
+    );
+    let map = BTreeMap::from([(1u8, -1i64), (2, 5)]);
+    let converted: BTreeMap<u8, i16> = map_values_cinto(map).unwrap();
+    assert_eq!(converted, BTreeMap::from([(1, -1), (2, 5)]));
+}
+
+#[test]
+fn const_operands() {
+    use crate::ops::{cadd_const_u32, cadd_const_u64, cmul_const_u32, cmul_const_u64};
+
+    assert_eq!(cadd_const_u32::<10>(u32::MAX - 10).unwrap(), u32::MAX);
+    assert_err(
+        cadd_const_u32::<10>(u32::MAX - 9),
+        "overflow: 4294967286 + 10",
+    );
+    assert_eq!(cadd_const_u64::<0>(u64::MAX).unwrap(), u64::MAX);
+    assert_eq!(cmul_const_u32::<0>(u32::MAX).unwrap(), 0);
+    assert_eq!(cmul_const_u64::<2>(u64::MAX / 2).unwrap(), u64::MAX - 1);
+    assert_err(
+        cmul_const_u64::<3>(1 << 63),
+        "overflow: 9223372036854775808 * 3",
+    );
+}
+
+#[test]
+fn write_message_to_fixed_buffer() {
+    use core::fmt::{self, Write};
+
+    struct Buffer {
+        data: [u8; 8],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.data
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let err = crate::Error::new("too long message".into());
+    let mut buf = Buffer {
+        data: [0; 8],
+        len: 0,
+    };
+    assert!(err.write_message(&mut buf).is_err());
+    let err = crate::Error::new("short".into());
+    let mut buf = Buffer {
+        data: [0; 8],
+        len: 0,
+    };
+    err.write_message(&mut buf).unwrap();
+    assert_eq!(&buf.data[..buf.len], b"short");
+}
+
+#[test]
+fn non_zero_from_str() {
+    use core::num::NonZero;
+
+    assert_err(
+        NonZero::<u32>::cfrom(""),
+        r#"cannot parse "" as NonZero<u32>: not a number"#,
+    );
+    assert_err(
+        NonZero::<u32>::cfrom("0"),
+        r#"cannot parse "0" as NonZero<u32>: zero is not allowed"#,
+    );
+    assert_eq!(NonZero::<u32>::cfrom("5").unwrap().get(), 5);
+    assert_err(
+        NonZero::<u32>::cfrom("4294967296"),
+        r#"cannot parse "4294967296" as NonZero<u32>: number is out of bounds"#,
+    );
+    assert_err(
+        NonZero::<u8>::cfrom("-1"),
+        r#"cannot parse "-1" as NonZero<u8>: not a number"#,
+    );
+    assert_eq!(NonZero::<u128>::cfrom("+7").unwrap().get(), 7);
+}
+
+#[test]
+fn div_exact() {
+    assert_eq!(cdiv_exact(-12i8, 4).unwrap(), -3);
+    assert_eq!(cdiv_exact(0u64, 7).unwrap(), 0);
+    assert_err(
+        cdiv_exact(-13i8, 4),
+        "division is not exact: -13 / 4 (remainder -1)",
+    );
+    assert_err(cdiv_exact(1u8, 0), "division by zero: 1 / 0");
+    assert_err(cdiv_exact(i32::MIN, -1), "overflow: -2147483648 / -1");
+    assert_eq!(cdiv_exact(5i32, 2).unwrap_err().category(), "div_exact");
+}
+
+#[test]
+fn ccmp_matches_i128() {
+    let signed = [
+        i64::MIN,
+        i64::from(i32::MIN),
+        -1,
+        0,
+        1,
+        i64::from(u32::MAX),
+        i64::MAX,
+    ];
+    let unsigned = [0, 1, u64::from(u32::MAX), i64::MAX as u64, u64::MAX];
+    for a in signed {
+        for b in unsigned {
+            assert_eq!(ccmp(a, b), i128::from(a).cmp(&i128::from(b)), "{a} {b}");
+            assert_eq!(ccmp(b, a), i128::from(b).cmp(&i128::from(a)), "{b} {a}");
+            if let (Ok(a32), Ok(b16)) = (i32::try_from(a), u16::try_from(b)) {
+                assert_eq!(ccmp(a32, b16), i128::from(a32).cmp(&i128::from(b16)));
+            }
+        }
+    }
+    assert_eq!(ccmp(u128::MAX, u128::MAX - 1), core::cmp::Ordering::Greater);
+    assert_eq!(
+        ccmp(i128::MAX as u128, i128::MAX),
+        core::cmp::Ordering::Equal
+    );
+    assert_eq!(ccmp(i128::MIN, 0usize), core::cmp::Ordering::Less);
+}
+
+#[test]
+fn duration_from_f64() {
+    use {
+        crate::time::{duration_from_millis_f64_checked, duration_from_secs_f64_checked},
+        core::time::Duration,
+    };
+
+    assert_eq!(
+        duration_from_secs_f64_checked(0.25).unwrap(),
+        Duration::from_millis(250)
+    );
+    assert_eq!(
+        duration_from_secs_f64_checked(-0.0).unwrap(),
+        Duration::ZERO
+    );
+    assert_err(
+        duration_from_secs_f64_checked(-1.0),
+        "cannot convert -1 seconds to Duration: value is negative",
+    );
+    assert_err(
+        duration_from_secs_f64_checked(f64::NAN),
+        "cannot convert NaN seconds to Duration: value is NaN",
+    );
+    assert_err(
+        duration_from_secs_f64_checked(f64::NEG_INFINITY),
+        "cannot convert -inf seconds to Duration: value is infinite",
+    );
+    assert_err(
+        duration_from_secs_f64_checked(1e20),
+        "cannot convert 100000000000000000000 seconds to Duration: value is out of bounds",
+    );
+    assert_eq!(
+        duration_from_millis_f64_checked(1500.0).unwrap(),
+        Duration::from_millis(1500)
+    );
+    assert_err(
+        duration_from_millis_f64_checked(f64::INFINITY),
+        "cannot convert inf milliseconds to Duration: value is infinite",
+    );
+    assert_err(
+        duration_from_millis_f64_checked(1e23),
+        "cannot convert 100000000000000000000000 milliseconds to Duration: value is out of bounds",
+    );
+}
+
+#[test]
+fn advance_at_max() {
+    use {crate::range::advance, core::num::NonZero};
+
+    let one = NonZero::new(1).unwrap();
+    assert_eq!(advance(usize::MAX - 1, one).unwrap(), usize::MAX);
+    assert_err(
+        advance(usize::MAX, one),
+        &std::format!("overflow: advance({}, 1)", usize::MAX),
+    );
+}
+
+#[test]
+fn scale_duration_without_drift() {
+    use {crate::time::scale_duration, core::num::NonZero, core::time::Duration};
+
+    let three = NonZero::new(3).unwrap();
+    let d = Duration::new(10_000_000_000, 1);
+    // The float path loses the last nanosecond even though the factor is exactly 1.
+    assert_ne!(d.mul_f64(3.0 / 3.0), d);
+    assert_eq!(scale_duration(d, 3, three).unwrap(), d);
+    let d = Duration::from_millis(1);
+    // The float path rounds instead of truncating.
+    assert_eq!(d.mul_f64(2.0 / 3.0), Duration::from_nanos(666_667));
+    assert_eq!(
+        scale_duration(d, 2, three).unwrap(),
+        Duration::from_nanos(666_666)
+    );
+    assert_eq!(
+        scale_duration(Duration::MAX, 0, three).unwrap(),
+        Duration::ZERO
+    );
+    assert_eq!(
+        scale_duration(Duration::MAX, u32::MAX, NonZero::new(u32::MAX).unwrap()).unwrap(),
+        Duration::MAX
+    );
+    assert_err(
+        scale_duration(
+            Duration::from_secs(u64::MAX / 2 + 1),
+            2,
+            NonZero::new(1).unwrap(),
+        ),
+        "overflow: scale_duration(9223372036854775808s, 2, 1)",
+    );
+}
+
+#[test]
+fn decode_vec_lengths() {
+    use crate::bytes::{decode_vec, Endian};
+
+    let bytes = [0x12, 0x34, 0x56, 0x78, 0xFF, 0xFF, 0xFF, 0xFE];
+    assert_eq!(
+        decode_vec::<u32>(&bytes, Endian::Big).unwrap(),
+        [0x12345678, 0xFFFFFFFE]
+    );
+    assert_eq!(decode_vec::<i32>(&bytes[4..], Endian::Big).unwrap(), [-2]);
+    assert_eq!(decode_vec::<u64>(&[], Endian::Little).unwrap(), []);
+    assert_err(
+        decode_vec::<u32>(&bytes[..7], Endian::Little),
+        "cannot decode u32 values from 7 bytes: length is not a multiple of 4 (remainder 3)",
+    );
+}
+
+#[test]
+fn bit_field_offsets() {
+    assert_eq!(1u8.place_at(7).unwrap(), 0x80);
+    assert_eq!(0u8.place_at(7).unwrap(), 0);
+    assert_err(0u8.place_at(8), "field doesn't fit: place_at(0, 8) for u8");
+    assert_err(
+        1u32.place_at(u32::MAX),
+        "field doesn't fit: place_at(1, 4294967295) for u32",
+    );
+    assert_eq!(u64::MAX.extract_at(0, 64).unwrap(), u64::MAX);
+    assert_eq!(u64::MAX.extract_at(64, 0).unwrap(), 0);
+    assert_eq!(0xABCDu16.extract_at(8, 8).unwrap(), 0xAB);
+    assert_err(
+        0xABCDu16.extract_at(9, 8),
+        "field is out of bounds: extract_at(43981, 9, 8) for u16",
+    );
+    assert_err(
+        1u8.extract_at(u32::MAX, 2),
+        "field is out of bounds: extract_at(1, 4294967295, 2) for u8",
+    );
+}
+
+#[test]
+fn port_offsets_at_bounds() {
+    use {
+        crate::net::{next_port, with_port_offset},
+        std::net::SocketAddr,
+    };
+
+    let zero: SocketAddr = "10.0.0.1:0".parse().unwrap();
+    assert_eq!(next_port(zero).unwrap().port(), 1);
+    assert_eq!(with_port_offset(zero, 0).unwrap(), zero);
+    assert_err(
+        with_port_offset(zero, -1),
+        "port is out of range: 0 + -1 for 10.0.0.1:0",
+    );
+    let max: SocketAddr = "10.0.0.1:65535".parse().unwrap();
+    assert_eq!(with_port_offset(max, -65535).unwrap(), zero);
+    assert_err(
+        next_port(max),
+        "port is out of range: 65535 + 1 for 10.0.0.1:65535",
+    );
+    assert!(with_port_offset(max, i32::MAX).is_err());
+}
+
+#[test]
+fn with_capacity_overflow() {
+    assert_err(
+        crate::vec::cwith_capacity::<u32>(isize::MAX as usize / 4 + 1),
+        &std::format!(
+            "capacity overflow: {} elements of 4 bytes",
+            isize::MAX as usize / 4 + 1
+        ),
+    );
+    assert!(crate::vec::cwith_capacity::<()>(usize::MAX).is_ok());
+    assert_err(
+        crate::string::cwith_capacity(isize::MAX as usize + 1),
+        &std::format!("capacity overflow: {} bytes", isize::MAX as usize + 1),
+    );
+    let err = crate::hash_map::cwith_capacity::<u64, u64>(usize::MAX).unwrap_err();
+    assert!(err.message().starts_with(&std::format!(
+        "cannot allocate {} entries of 16 bytes: ",
+        usize::MAX
+    )));
+}
+
+#[test]
+fn fold_references() {
+    let values = [3i64, -4, 5];
+    let product = values.iter().try_fold(1, |acc, x| cmul(acc, x)).unwrap();
+    assert_eq!(product, -60);
+    assert_eq!(cadd(&values[0], &values[1]).unwrap(), -1);
+    assert_eq!((&values[2]).csub(1).unwrap(), 4);
+    assert_eq!(cdiv_euclid(&values[1], &values[0]).unwrap(), -2);
+    let values = [u8::MAX, 1];
+    assert_err(
+        values.iter().try_fold(0u8, |acc, x| cadd(acc, x)),
+        "overflow: 255 + 1",
+    );
+    assert_err(crem(&values[0], &0), "division by zero: 255 % 0");
+}
+
+#[test]
+fn duration_components_ranges() {
+    use {crate::time::duration_components, core::time::Duration};
+
+    let d = Duration::from_secs(400 * 86_400 + 23 * 3_600 + 59 * 60 + 59);
+    assert_eq!(duration_components(d), (400, 23, 59, 59, 0));
+    assert_eq!(
+        duration_components(Duration::from_nanos(999_999_999)),
+        (0, 0, 0, 0, 999_999_999)
+    );
+    assert_eq!(
+        duration_components(Duration::from_millis(61_500)),
+        (0, 0, 1, 1, 500_000_000)
+    );
+    assert_eq!(
+        duration_components(Duration::MAX),
+        (u64::MAX / 86_400, 7, 0, 15, 999_999_999)
+    );
+}
+
+#[test]
+fn char_to_byte_lossy() {
+    use crate::convert::{char_to_ascii_lossy, SaturatingFrom};
+
+    assert_eq!(char_to_ascii_lossy('A', b'?'), b'A');
+    assert_eq!(char_to_ascii_lossy('\u{e9}', b'?'), b'?');
+    assert_eq!(char_to_ascii_lossy('\u{1F600}', b'_'), b'_');
+    assert_eq!(u8::saturating_from('A'), b'A');
+    assert_eq!(u8::saturating_from('\u{e9}'), b'?');
+    assert_eq!(u8::saturating_from('\u{1F600}'), b'?');
+    assert_eq!(u8::saturating_from('\u{FF}'), b'?');
+    assert_eq!(u8::saturating_from('\u{7F}'), 0x7F);
+}
+
+#[test]
+fn widening_sum() {
+    use crate::accumulate::{cwsum, wsum};
+
+    let values = core::iter::repeat_n(u32::MAX, 1000);
+    assert_eq!(wsum::<u32, u64>(values.clone()), 1000 * u64::from(u32::MAX));
+    assert_eq!(
+        cwsum::<u32, u64>(values).unwrap(),
+        1000 * u64::from(u32::MAX)
+    );
+    assert_eq!(wsum::<u64, u128>([u64::MAX; 4]), 4 * u128::from(u64::MAX));
+    assert_eq!(wsum::<u64, u64>([u64::MAX, 1]), u64::MAX);
+    assert_eq!(wsum::<i64, i64>([i64::MIN, -1]), i64::MIN);
+    assert_eq!(wsum::<u8, u16>([]), 0);
+    assert_err(
+        cwsum::<u64, u64>([u64::MAX, 0, 1]),
+        "overflow adding values: running sum 18446744073709551615 + 1 at index 2",
+    );
+}
+
+#[test]
+fn error_fields() {
+    use std::string::ToString;
+
+    let err = cadd(i32::MAX, 1i32)
+        .unwrap_err()
+        .with_field("user_id", 42)
+        .with_field("op", "\"sum\"");
+    assert_eq!(err.message(), "overflow: 2147483647 + 1");
+    assert_eq!(
+        err.fields(),
+        [("user_id", "42".to_string()), ("op", "\"sum\"".to_string())]
+    );
+    assert!(std::format!("{err:?}")
+        .starts_with("overflow: 2147483647 + 1\nfields: user_id=42, op=\"sum\""));
+    assert!(std::format!("{err}").starts_with("overflow: 2147483647 + 1"));
+    assert!(!std::format!("{err}").contains("user_id="));
+    assert!(err
+        .json()
+        .to_string()
+        .ends_with(r#","fields":{"user_id":"42","op":"\"sum\""}}"#));
+}
+
+#[test]
+fn pack_and_unpack_bits() {
+    use crate::ops::{pack_bits, unpack_bits};
+
+    assert_eq!(pack_bits::<u64>(&[]).unwrap(), 0);
+    assert_eq!(pack_bits::<u16>(&[true; 16]).unwrap(), u16::MAX);
+    assert_err(
+        pack_bits::<u16>(&[true; 17]),
+        "too many bits to pack into u16: 17",
+    );
+    assert_err(
+        pack_bits::<u64>(&[false; 65]),
+        "too many bits to pack into u64: 65",
+    );
+    let bits = [true, true, false, false, true, false, false, true];
+    let packed = pack_bits::<u8>(&bits).unwrap();
+    assert_eq!(packed, 0b1001_0011);
+    assert_eq!(unpack_bits::<u8, 8>(packed), bits);
+    assert_eq!(unpack_bits::<u8, 10>(u8::MAX)[7..], [true, false, false]);
+    assert_eq!(unpack_bits::<u128, 0>(1), []);
+}
+
+#[test]
+fn dot_product() {
+    use crate::accumulate::cdot;
+
+    assert_eq!(cdot::<u64>(&[], &[]).unwrap(), 0);
+    assert_err(
+        cdot(&[1i64, 2, 3], &[1, 2]),
+        "cannot compute dot product: lengths are different (3 and 2)",
+    );
+    assert_err(
+        cdot(&[i16::MAX, 1], &[1, 1]),
+        "overflow in dot product at index 1: running sum 32767 + 1 * 1",
+    );
+    assert_err(
+        cdot(&[0u32, 1 << 16], &[5, 1 << 16]),
+        "overflow in dot product at index 1: running sum 0 + 65536 * 65536",
+    );
+    assert_eq!(cdot(&[-3i8, 4], &[40, 2]).unwrap(), -112);
+}
+
+#[test]
+fn grouped_integers() {
+    use crate::parse::{parse_int_grouped, Grouping};
+
+    assert_eq!(
+        parse_int_grouped::<u64>("1,234,567", ',', Grouping::Strict).unwrap(),
+        1_234_567
+    );
+    assert_eq!(
+        parse_int_grouped::<i32>("+999", ',', Grouping::Strict).unwrap(),
+        999
+    );
+    assert_eq!(
+        parse_int_grouped::<i8>("-1_28", '_', Grouping::Lenient).unwrap(),
+        -128
+    );
+    assert_err(
+        parse_int_grouped::<u64>("1234,567", ',', Grouping::Strict),
+        r#"cannot parse "1234,567" as u64: missing group separator"#,
+    );
+    assert_err(
+        parse_int_grouped::<u32>("1234", ',', Grouping::Strict),
+        r#"cannot parse "1234" as u32: missing group separator"#,
+    );
+    assert_err(
+        parse_int_grouped::<u32>("1,23", ',', Grouping::Strict),
+        r#"cannot parse "1,23" as u32: misplaced separator"#,
+    );
+    assert_eq!(
+        parse_int_grouped::<u32>("1234", ',', Grouping::Lenient).unwrap(),
+        1234
+    );
+    assert_err(
+        parse_int_grouped::<u32>("213", '1', Grouping::Lenient),
+        r#"cannot parse "213" as u32: invalid separator '1'"#,
+    );
+    assert_err(
+        parse_int_grouped::<i32>("-1-234", '-', Grouping::Strict),
+        r#"cannot parse "-1-234" as i32: invalid separator '-'"#,
+    );
+    assert_err(
+        parse_int_grouped::<i32>("1+234", '+', Grouping::Lenient),
+        r#"cannot parse "1+234" as i32: invalid separator '+'"#,
+    );
+    assert_err(
+        parse_int_grouped::<u64>("1,,234", ',', Grouping::Lenient),
+        r#"cannot parse "1,,234" as u64: misplaced separator"#,
+    );
+    assert_err(
+        parse_int_grouped::<u64>(",234", ',', Grouping::Lenient),
+        r#"cannot parse ",234" as u64: misplaced separator"#,
+    );
+    assert_err(
+        parse_int_grouped::<u64>("1.234", ',', Grouping::Lenient),
+        r#"cannot parse "1.234" as u64: invalid digit found in string"#,
+    );
+    assert_err(
+        parse_int_grouped::<u64>("-", ',', Grouping::Lenient),
+        r#"cannot parse "-" as u64: no digits"#,
+    );
+    assert_err(
+        parse_int_grouped::<u8>("256", ',', Grouping::Strict),
+        r#"cannot parse "256" as u8: number is out of bounds"#,
+    );
+}
+
+#[test]
+fn unix_timestamps() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    assert_eq!(SystemTime::cfrom(0i64).unwrap(), UNIX_EPOCH);
+    assert_eq!(i64::cfrom(UNIX_EPOCH).unwrap(), 0);
+
+    let future = SystemTime::cfrom(32_503_680_000i64).unwrap();
+    assert_eq!(future, UNIX_EPOCH + Duration::from_secs(32_503_680_000));
+    assert_eq!(
+        i64::cfrom(future + Duration::from_millis(999)).unwrap(),
+        32_503_680_000
+    );
+
+    let past = SystemTime::cfrom(-86_400i64).unwrap();
+    assert_eq!(past, UNIX_EPOCH - Duration::from_secs(86_400));
+    assert_eq!(i64::cfrom(past).unwrap(), -86_400);
+    // Rounded down, not toward zero.
+    assert_eq!(
+        i64::cfrom(UNIX_EPOCH - Duration::from_millis(1500)).unwrap(),
+        -2
+    );
+
+    if let Ok(max) = SystemTime::cfrom(i64::MAX) {
+        assert_eq!(i64::cfrom(max).unwrap(), i64::MAX);
+    }
+}
+
+#[test]
+fn system_time_offset_secs() {
+    use {
+        crate::time::CoffsetSecs,
+        std::time::{SystemTime, UNIX_EPOCH},
+    };
+
+    assert_eq!(
+        UNIX_EPOCH.coffset_secs(-5).unwrap(),
+        SystemTime::cfrom(-5i64).unwrap()
+    );
+    assert_eq!(UNIX_EPOCH.coffset_secs(0).unwrap(), UNIX_EPOCH);
+    // `SystemTime` covers the whole `i64` range of seconds on Unix.
+    #[cfg(unix)]
+    {
+        let max = UNIX_EPOCH.coffset_secs(i64::MAX).unwrap();
+        assert_eq!(i64::cfrom(max).unwrap(), i64::MAX);
+        let err = max.coffset_secs(1).unwrap_err();
+        assert!(err.message().starts_with("overflow: offset_secs("));
+        assert_eq!(err.category(), "offset_secs");
+        let min = UNIX_EPOCH.coffset_secs(i64::MIN).unwrap();
+        assert_eq!(i64::cfrom(min).unwrap(), i64::MIN);
+        assert!(min.coffset_secs(-1).is_err());
+        assert_eq!(
+            min.coffset_secs(i64::MAX).unwrap(),
+            UNIX_EPOCH.coffset_secs(-1).unwrap()
+        );
+    }
+}
+
+#[test]
+fn non_zero_from_ops() {
+    use {crate::convert::NonZeroFromOps, core::num::NonZero};
+
+    let two = NonZero::new(2u8).unwrap();
+    let product: NonZero<u8> = NonZero::cfrom_product(two, NonZero::new(127).unwrap()).unwrap();
+    assert_eq!(product.get(), 254);
+    assert_err(
+        NonZero::cfrom_product(two, NonZero::new(128u8).unwrap()),
+        "overflow: 2 * 128",
+    );
+    let sum: NonZero<u64> =
+        NonZero::cfrom_sum(NonZero::<u64>::MIN, NonZero::new(u64::MAX - 1).unwrap()).unwrap();
+    assert_eq!(sum, NonZero::<u64>::MAX);
+    assert_err(
+        NonZero::cfrom_sum(NonZero::<u64>::MAX, NonZero::<u64>::MIN),
+        "overflow: 18446744073709551615 + 1",
+    );
+}
+
+#[test]
+fn long_message_truncated() {
+    use std::string::String;
+
+    let err = crate::Error::new("é".repeat(3000));
+    let expected = String::from("é").repeat(2048) + "…[truncated 1904 bytes]";
+    assert_eq!(err.message(), expected);
+    let err = crate::Error::new("x".repeat(4096));
+    assert_eq!(err.message().len(), 4096);
+}
+
+#[test]
+fn duration_div_rem() {
+    use {crate::time::CdivRemDuration, core::time::Duration};
+
+    let result = Duration::from_secs(10).cdiv_rem_duration(Duration::from_secs(2));
+    assert_eq!(result.unwrap(), (5, Duration::ZERO));
+    let result = Duration::from_millis(2500).cdiv_rem_duration(Duration::from_millis(700));
+    assert_eq!(result.unwrap(), (3, Duration::from_millis(400)));
+    let result = Duration::from_nanos(5).cdiv_rem_duration(Duration::from_secs(1));
+    assert_eq!(result.unwrap(), (0, Duration::from_nanos(5)));
+    assert_err(
+        Duration::from_secs(1).cdiv_rem_duration(Duration::ZERO),
+        "division by zero: cdiv_rem_duration(1s, 0ns)",
+    );
+    assert_err(
+        Duration::from_secs(5).cdiv_rem_duration(Duration::from_nanos(1)),
+        "overflow: cdiv_rem_duration(5s, 1ns)",
+    );
+    let result = Duration::MAX.cdiv_rem_duration(Duration::from_secs(u64::MAX / 2));
+    assert_eq!(result.unwrap(), (2, Duration::new(1, 999_999_999)));
+}
+
+#[test]
+fn len_prefixed_str() {
+    use crate::bytes::read_len_prefixed_str;
+
+    let (text, rest) = read_len_prefixed_str(b"\0\0\0\x03abc\0\0").unwrap();
+    assert_eq!((text, rest), ("abc", &b"\0\0"[..]));
+    assert_eq!(read_len_prefixed_str(b"\0\0\0\0").unwrap(), ("", &b""[..]));
+    assert_err(
+        read_len_prefixed_str(b"\0\0"),
+        "cannot read length-prefixed string: expected 4 length bytes, got 2",
+    );
+    let err = read_len_prefixed_str(b"\0\0\x01\0abc").unwrap_err();
+    assert_eq!(err.category(), "truncated");
+    assert_eq!(
+        err.message(),
+        "cannot read length-prefixed string: expected 256 payload bytes, got 3"
+    );
+    let err = read_len_prefixed_str(b"\0\0\0\x02a\xFF").unwrap_err();
+    assert_eq!(err.category(), "convert");
+    assert_eq!(
+        err.message(),
+        "cannot read length-prefixed string: not a utf-8 string: \
+         invalid utf-8 sequence of 1 bytes from index 1"
+    );
+}
+
+#[test]
+fn bounded_ops() {
+    use crate::ops::Cbounded;
+
+    assert_eq!(999u16.cadd_bounded(1, 1000).unwrap(), 1000);
+    let err = 999u16.cadd_bounded(2, 1000).unwrap_err();
+    assert_eq!(err.category(), "limit");
+    assert_eq!(err.message(), "exceeds limit 1000: 1001");
+    assert_err(u16::MAX.cadd_bounded(1, 1000), "overflow: 65535 + 1");
+    assert_eq!((-5i8).cadd_bounded(-100, 10).unwrap(), -105);
+    assert_err(60i8.cadd_bounded(60, 100), "exceeds limit 100: 120");
+    assert_eq!(25u64.cmul_bounded(40, 1000).unwrap(), 1000);
+    assert_err(25u64.cmul_bounded(41, 1000), "exceeds limit 1000: 1025");
+    assert_err(i32::MAX.cmul_bounded(2, 0), "overflow: 2147483647 * 2");
+}
+
+#[test]
+fn ctry_inference() {
+    use crate::convert::{CTry, IntoType};
+
+    fn narrow(value: u64) -> crate::Result<u8> {
+        let narrowed: u8 = value.ctry()?;
+        Ok(narrowed)
+    }
+    fn widen(value: u8) -> crate::Result<i128> {
+        value.ctry()
+    }
+
+    assert_eq!(narrow(255).unwrap(), 255);
+    assert_err(
+        narrow(256),
+        "cannot convert value 256 from u64 to u8: value is out of bounds",
+    );
+    assert_eq!(widen(7).unwrap(), 7);
+    assert_eq!(1 + (-1i64).cinto_type::<i8>().unwrap(), 0);
+    assert!((-1i64).cinto_type::<u8>().is_err());
+}
+
+#[test]
+fn error_builder() {
+    use core::panic::Location;
+
+    let err = crate::Error::builder("limit reached".into()).build();
+    assert_eq!(err.message(), "limit reached");
+    assert_eq!(err.category(), "other");
+    assert!(!err.is_retryable());
+    assert!(err.fields().is_empty());
+    assert_eq!(err.location().line(), line!() - 5);
+
+    let location = Location::caller();
+    let err = crate::Error::builder("overflow: 200 * 2".into())
+        .category("mul")
+        .retryable(true)
+        .location(location)
+        .field("item", "a")
+        .field("count", 2)
+        .build();
+    assert_eq!(err.message(), "overflow: 200 * 2");
+    assert_eq!(err.category(), "mul");
+    assert!(err.is_retryable());
+    assert_eq!(err.location(), location);
+    assert_eq!(err.fields(), [("item", "a".into()), ("count", "2".into())]);
+}
+
+#[test]
+fn basis_points() {
+    use crate::ops::{apply_basis_points, RoundingMode::*};
+
+    assert_eq!(apply_basis_points(10_000, 1, Floor).unwrap(), 1);
+    assert_eq!(apply_basis_points(12_345, 0, Ceil).unwrap(), 0);
+    assert_eq!(apply_basis_points(5, 1_000, HalfAwayFromZero).unwrap(), 1);
+    assert_eq!(apply_basis_points(5, 1_000, HalfEven).unwrap(), 0);
+    assert_eq!(apply_basis_points(15, 1_000, HalfEven).unwrap(), 2);
+    assert_eq!(apply_basis_points(1, 1, Ceil).unwrap(), 1);
+    assert_eq!(apply_basis_points(1, 1, TowardZero).unwrap(), 0);
+    // `amount * bps` overflows `u64`, but the result fits.
+    assert_eq!(
+        apply_basis_points(u64::MAX, 9_999, Floor).unwrap(),
+        18_444_899_399_302_180_659
+    );
+    assert_eq!(
+        apply_basis_points(u64::MAX, 10_000, Floor).unwrap(),
+        u64::MAX
+    );
+    assert_eq!(
+        apply_basis_points(u64::MAX / 3, 30_000, Floor).unwrap(),
+        u64::MAX
+    );
+    assert_err(
+        apply_basis_points(u64::MAX, 10_001, Floor),
+        "overflow: apply_basis_points(18446744073709551615, 10001)",
+    );
+}
+
+#[test]
+fn percent() {
+    use {crate::ops::percent_of, core::num::NonZero};
+
+    let hundred = NonZero::new(100u64).unwrap();
+    assert_eq!(percent_of(0, hundred), 0);
+    assert_eq!(percent_of(99, hundred), 99);
+    assert_eq!(percent_of(100, hundred), 100);
+    assert_eq!(percent_of(101, hundred), 100);
+    assert_eq!(percent_of(u64::MAX, hundred), 100);
+    assert_eq!(percent_of(0, NonZero::<u64>::MIN), 0);
+    assert_eq!(percent_of(u64::MAX - 1, NonZero::<u64>::MAX), 99);
+    assert_eq!(percent_of(1, NonZero::<u64>::MAX), 0);
+}
+
+#[test]
+fn add_indexed() {
+    use {crate::accumulate::cadd_indexed, std::vec::Vec};
+
+    assert_eq!(cadd_indexed::<u32>(&[]).unwrap(), 0);
+    assert_eq!(cadd_indexed(&[-5i8, 100, -128, 60]).unwrap(), 27);
+    assert_err(
+        cadd_indexed(&[-100i8, -28, -1]),
+        "overflow at index 2: -128 + -1",
+    );
+    let mut values = Vec::from([1u64; 1000]);
+    values[700] = u64::MAX - 700;
+    assert_err(
+        cadd_indexed(&values),
+        "overflow at index 701: 18446744073709551615 + 1",
+    );
+    let values = [usize::MAX / 2, usize::MAX / 2, 1];
+    assert_eq!(cadd_indexed(&values).unwrap(), usize::MAX);
+}
+
+#[test]
+fn reverse_conversions() {
+    use {
+        crate::convert::{IntoType, SaturatingFrom},
+        core::cmp::Reverse,
+    };
+
+    let value: Reverse<u32> = Reverse(4_000_000_000u64).cinto_type().unwrap();
+    assert_eq!(value, Reverse(4_000_000_000));
+    assert_err(
+        Reverse(5_000_000_000u64).cinto_type::<Reverse<u32>>(),
+        "cannot convert value 5000000000 from u64 to u32: value is out of bounds",
+    );
+    assert_eq!(
+        Reverse::<u32>::saturating_from(Reverse(5_000_000_000u64)),
+        Reverse(u32::MAX)
+    );
+    assert_eq!(Reverse::<u8>::saturating_from(Reverse(-1i64)), Reverse(0));
+}
+
+#[test]
+fn option_ops() {
+    use crate::ops::{Cadd, CaddOption, Cdiv, Cmul, Csub};
+
+    fn mean_opt(total: i8, extra: i8, count: i8) -> Option<i8> {
+        total.cadd_opt(extra)?.cdiv_opt(count)
+    }
+    fn mean(total: i8, extra: i8, count: i8) -> crate::Result<i8> {
+        total.cadd(extra)?.cdiv(count)
+    }
+
+    assert_eq!(mean_opt(10, 20, 3), Some(10));
+    assert_eq!(mean_opt(100, 100, 2), None);
+    assert_eq!(mean_opt(10, 20, 0), None);
+    assert_eq!(mean_opt(-128, 0, -1), None);
+    assert_err(mean(100, 100, 2), "overflow: 100 + 100");
+    assert_err(mean(10, 20, 0), "division by zero: 30 / 0");
+
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            assert_eq!(a.cadd_opt(b), a.cadd(b).ok());
+            assert_eq!(a.csub_opt(b), a.csub(b).ok());
+            assert_eq!(a.cmul_opt(b), a.cmul(b).ok());
+            assert_eq!(a.cdiv_opt(b), a.cdiv(b).ok());
+        }
+    }
+    assert_eq!(u64::MAX.cmul_opt(1), Some(u64::MAX));
+    assert_eq!(0usize.csub_opt(1), None);
+}
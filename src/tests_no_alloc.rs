@@ -0,0 +1,51 @@
+use {
+    crate::{error::error, Error},
+    std::string::{String, ToString},
+};
+
+#[test]
+fn short_message() {
+    let err = error!("other", "overflow: {} + {}", 200u8, 100u8);
+    assert_eq!(err.message(), "overflow: 200 + 100");
+    assert_eq!(err.to_string(), "overflow: 200 + 100");
+}
+
+#[test]
+fn message_at_capacity() {
+    let text = "a".repeat(Error::INLINE_CAPACITY);
+    let err = error!("other", "{}", text);
+    assert_eq!(err.message(), text);
+}
+
+#[test]
+fn long_message_truncated() {
+    let text = "a".repeat(200);
+    let err = error!("other", "{}", text);
+    let expected = "a".repeat(Error::INLINE_CAPACITY - 3) + "…";
+    assert_eq!(err.message(), expected);
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn truncated_on_char_boundary() {
+    // "€" is 3 bytes long, so one of them straddles the capacity.
+    let text = String::from("ab") + &"€".repeat(50);
+    let err = error!("other", "{}", text);
+    // 2 + 41 * 3 = 125 bytes fit before the marker.
+    let expected = String::from("ab") + &"€".repeat(41) + "…";
+    assert_eq!(err.message(), expected);
+    assert_eq!(err.message().len(), Error::INLINE_CAPACITY);
+
+    let text = String::from("a") + &"€".repeat(50);
+    let err = error!("other", "{}", text);
+    // Only 1 + 41 * 3 = 124 bytes fit before the marker.
+    let expected = String::from("a") + &"€".repeat(41) + "…";
+    assert_eq!(err.message(), expected);
+}
+
+#[test]
+fn truncated_across_arguments() {
+    let err = error!("other", "{}{}", "x".repeat(100), "y".repeat(100));
+    let expected = "x".repeat(100) + &"y".repeat(25) + "…";
+    assert_eq!(err.message(), expected);
+}
@@ -0,0 +1,398 @@
+//! Checked operations on durations and time points.
+
+use {
+    crate::error::error,
+    core::{num::NonZero, time::Duration},
+};
+
+/// Unit of time for converting between [`Duration`] and integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    /// Nanoseconds.
+    Nanos,
+    /// Microseconds.
+    Micros,
+    /// Milliseconds.
+    Millis,
+    /// Seconds.
+    Secs,
+}
+
+impl DurationUnit {
+    /// Number of units in one second.
+    #[inline]
+    pub fn per_second(self) -> u32 {
+        match self {
+            DurationUnit::Nanos => 1_000_000_000,
+            DurationUnit::Micros => 1_000_000,
+            DurationUnit::Millis => 1_000,
+            DurationUnit::Secs => 1,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            DurationUnit::Nanos => "nanoseconds",
+            DurationUnit::Micros => "microseconds",
+            DurationUnit::Millis => "milliseconds",
+            DurationUnit::Secs => "seconds",
+        }
+    }
+}
+
+/// Conversion between [`Duration`] and an integer number of [units](DurationUnit).
+/// ```
+/// use {cadd::time::{DurationUnit, DurationUnits}, std::time::Duration};
+///
+/// let duration = Duration::cfrom_units(1500, DurationUnit::Millis).unwrap();
+/// assert_eq!(duration, Duration::from_millis(1500));
+/// assert_eq!(duration.to_units(DurationUnit::Micros), 1_500_000);
+/// assert!(Duration::cfrom_units(u128::MAX, DurationUnit::Secs).is_err());
+/// ```
+pub trait DurationUnits: Sized {
+    /// Creates a duration from the number of units.
+    /// Returns an error if the value doesn't fit into `Duration`.
+    fn cfrom_units(value: u128, unit: DurationUnit) -> crate::Result<Self>;
+
+    /// Returns the number of whole units in the duration.
+    fn to_units(self, unit: DurationUnit) -> u128;
+}
+
+impl DurationUnits for Duration {
+    #[inline]
+    #[track_caller]
+    fn cfrom_units(value: u128, unit: DurationUnit) -> crate::Result<Self> {
+        let per_second = u128::from(unit.per_second());
+        let Ok(secs) = u64::try_from(value / per_second) else {
+            return Err(error!(
+                "convert",
+                "cannot convert {} {} to Duration: value is out of bounds",
+                value,
+                unit.name(),
+            ));
+        };
+        // Less than 1_000_000_000 because `value % per_second < per_second`.
+        let nanos = (value % per_second) as u32 * (1_000_000_000 / unit.per_second());
+        Ok(Duration::new(secs, nanos))
+    }
+
+    #[inline]
+    fn to_units(self, unit: DurationUnit) -> u128 {
+        match unit {
+            DurationUnit::Nanos => self.as_nanos(),
+            DurationUnit::Micros => self.as_micros(),
+            DurationUnit::Millis => self.as_millis(),
+            DurationUnit::Secs => u128::from(self.as_secs()),
+        }
+    }
+}
+
+/// Applies a signed offset in nanoseconds to a [`Duration`].
+/// ```
+/// use {cadd::time::CoffsetNanos, std::time::Duration};
+///
+/// let base = Duration::from_millis(10);
+/// assert_eq!(base.coffset_nanos(-500).unwrap(), Duration::from_nanos(9_999_500));
+/// assert_eq!(base.coffset_nanos(500).unwrap(), Duration::from_nanos(10_000_500));
+/// assert!(base.coffset_nanos(-20_000_000).is_err());
+/// ```
+pub trait CoffsetNanos: Sized {
+    /// Adds `delta` nanoseconds if it's positive or subtracts its absolute value if it's negative.
+    /// Returns an error if the result is negative or exceeds `Duration::MAX`.
+    fn coffset_nanos(self, delta: i64) -> crate::Result<Self>;
+}
+
+impl CoffsetNanos for Duration {
+    #[inline]
+    #[track_caller]
+    fn coffset_nanos(self, delta: i64) -> crate::Result<Self> {
+        let offset = Duration::from_nanos(delta.unsigned_abs());
+        let result = if delta < 0 {
+            self.checked_sub(offset)
+        } else {
+            self.checked_add(offset)
+        };
+        match result {
+            Some(value) => Ok(value),
+            None => Err(error!(
+                "offset_nanos",
+                "{}: offset_nanos({:?}, {})",
+                if delta < 0 {
+                    "result is negative"
+                } else {
+                    "overflow"
+                },
+                self,
+                delta,
+            )),
+        }
+    }
+}
+
+/// Division of a [`Duration`] by another `Duration`.
+/// ```
+/// use {cadd::time::CdivRemDuration, std::time::Duration};
+///
+/// let (frames, rest) = Duration::from_millis(1000).cdiv_rem_duration(Duration::from_millis(16)).unwrap();
+/// assert_eq!((frames, rest), (62, Duration::from_millis(8)));
+/// assert!(Duration::from_secs(1).cdiv_rem_duration(Duration::ZERO).is_err());
+/// ```
+pub trait CdivRemDuration: Sized {
+    /// Returns the number of whole `other` intervals that fit into `self` and the remaining duration.
+    /// Returns an error if `other` is zero or if the number of intervals doesn't fit into `u32`.
+    fn cdiv_rem_duration(self, other: Self) -> crate::Result<(u32, Self)>;
+}
+
+impl CdivRemDuration for Duration {
+    #[inline]
+    #[track_caller]
+    fn cdiv_rem_duration(self, other: Self) -> crate::Result<(u32, Self)> {
+        let divisor = other.as_nanos();
+        if divisor == 0 {
+            return Err(error!(
+                "div",
+                "division by zero: cdiv_rem_duration({:?}, {:?})", self, other,
+            ));
+        }
+        let nanos = self.as_nanos();
+        let Ok(count) = u32::try_from(nanos / divisor) else {
+            return Err(error!(
+                "div",
+                "overflow: cdiv_rem_duration({:?}, {:?})", self, other,
+            ));
+        };
+        // The remainder is less than `other`, so it fits into `Duration`.
+        let rest = nanos % divisor;
+        let rest = Duration::new((rest / 1_000_000_000) as u64, (rest % 1_000_000_000) as u32);
+        Ok((count, rest))
+    }
+}
+
+/// Applies a signed offset in seconds to a point in time.
+///
+/// It's implemented for [`SystemTime`](std::time::SystemTime) (requires the `std` feature).
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use {cadd::{convert::Cfrom, time::CoffsetSecs}, std::time::SystemTime};
+///
+/// let time = SystemTime::cfrom(1_000i64).unwrap();
+/// assert_eq!(time.coffset_secs(-400).unwrap(), SystemTime::cfrom(600i64).unwrap());
+/// assert_eq!(time.coffset_secs(400).unwrap(), SystemTime::cfrom(1_400i64).unwrap());
+/// # }
+/// ```
+pub trait CoffsetSecs: Sized {
+    /// Adds `delta` seconds if it's positive or subtracts its absolute value if it's negative.
+    /// Returns an error if the result is out of the representable range.
+    fn coffset_secs(self, delta: i64) -> crate::Result<Self>;
+}
+
+#[cfg(feature = "std")]
+impl CoffsetSecs for std::time::SystemTime {
+    #[inline]
+    #[track_caller]
+    fn coffset_secs(self, delta: i64) -> crate::Result<Self> {
+        let offset = Duration::from_secs(delta.unsigned_abs());
+        let result = if delta < 0 {
+            self.checked_sub(offset)
+        } else {
+            self.checked_add(offset)
+        };
+        match result {
+            Some(value) => Ok(value),
+            None => Err(error!(
+                "offset_secs",
+                "overflow: offset_secs({:?}, {})", self, delta,
+            )),
+        }
+    }
+}
+
+/// Converts a number of ticks of a clock with the frequency `hz` to a [`Duration`].
+///
+/// The result is truncated to whole nanoseconds. Unlike [`duration_to_ticks`], this conversion never fails
+/// because the number of whole seconds never exceeds `ticks`.
+/// ```
+/// use {cadd::time::duration_from_ticks, std::{num::NonZero, time::Duration}};
+///
+/// let hz = NonZero::new(32_768).unwrap();
+/// assert_eq!(duration_from_ticks(49_152, hz), Duration::from_millis(1500));
+/// ```
+#[inline]
+pub fn duration_from_ticks(ticks: u64, hz: NonZero<u32>) -> Duration {
+    let hz = u64::from(hz.get());
+    // `ticks % hz < hz <= u32::MAX`, so the multiplication doesn't overflow
+    // and the result is less than 1_000_000_000.
+    let nanos = (ticks % hz) * 1_000_000_000 / hz;
+    Duration::new(ticks / hz, nanos as u32)
+}
+
+/// Converts a [`Duration`] to a number of ticks of a clock with the frequency `hz`.
+///
+/// The result is truncated to whole ticks. Returns an error if it doesn't fit into `u64`.
+/// ```
+/// use {cadd::time::duration_to_ticks, std::{num::NonZero, time::Duration}};
+///
+/// let hz = NonZero::new(1000).unwrap();
+/// assert_eq!(duration_to_ticks(Duration::from_micros(1500), hz).unwrap(), 1);
+/// assert!(duration_to_ticks(Duration::MAX, hz).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn duration_to_ticks(d: Duration, hz: NonZero<u32>) -> crate::Result<u64> {
+    // Can't overflow: `as_nanos()` is less than 2^94 and `hz` is less than 2^32.
+    let ticks = d.as_nanos() * u128::from(hz.get()) / 1_000_000_000;
+    match u64::try_from(ticks) {
+        Ok(ticks) => Ok(ticks),
+        Err(_) => Err(error!(
+            "convert",
+            "cannot convert {:?} to ticks at {} Hz: value is out of bounds", d, hz,
+        )),
+    }
+}
+
+/// Sum of all durations. Returns zero for an empty iterator.
+///
+/// On overflow, the error message contains the running sum and the index of the duration
+/// that caused the overflow.
+/// ```
+/// use {cadd::time::duration_sum, std::time::Duration};
+///
+/// let durations = [Duration::from_secs(1), Duration::from_millis(500)];
+/// assert_eq!(duration_sum(durations).unwrap(), Duration::from_millis(1500));
+/// assert_eq!(
+///     duration_sum([Duration::from_secs(1), Duration::MAX]).unwrap_err().message(),
+///     "overflow adding durations: running sum 1s + 18446744073709551615.999999999s at index 1",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn duration_sum<I: IntoIterator<Item = Duration>>(durations: I) -> crate::Result<Duration> {
+    let mut total = Duration::ZERO;
+    for (index, value) in durations.into_iter().enumerate() {
+        total = match total.checked_add(value) {
+            Some(total) => total,
+            None => {
+                return Err(error!(
+                    "add",
+                    "overflow adding durations: running sum {:?} + {:?} at index {}",
+                    total,
+                    value,
+                    index,
+                ))
+            }
+        };
+    }
+    Ok(total)
+}
+
+/// Sum of all durations, clamped to [`Duration::MAX`]. Returns zero for an empty iterator.
+/// ```
+/// use {cadd::time::duration_sum_saturating, std::time::Duration};
+///
+/// assert_eq!(duration_sum_saturating([Duration::MAX, Duration::from_secs(1)]), Duration::MAX);
+/// ```
+#[inline]
+pub fn duration_sum_saturating<I: IntoIterator<Item = Duration>>(durations: I) -> Duration {
+    durations
+        .into_iter()
+        .fold(Duration::ZERO, Duration::saturating_add)
+}
+
+#[track_caller]
+fn duration_from_f64(value: f64, secs: f64, unit: &str) -> crate::Result<Duration> {
+    let reason = if value.is_nan() {
+        "value is NaN"
+    } else if value.is_infinite() {
+        "value is infinite"
+    } else if value < 0.0 {
+        "value is negative"
+    } else {
+        match Duration::try_from_secs_f64(secs) {
+            Ok(duration) => return Ok(duration),
+            Err(_) => "value is out of bounds",
+        }
+    };
+    Err(error!(
+        "convert",
+        "cannot convert {} {} to Duration: {}", value, unit, reason,
+    ))
+}
+
+/// Same as [`Duration::from_secs_f64`] but returns an error instead of panicking
+/// if the value is negative, NaN, infinite or too large.
+/// ```
+/// use {cadd::time::duration_from_secs_f64_checked, std::time::Duration};
+///
+/// assert_eq!(duration_from_secs_f64_checked(1.5).unwrap(), Duration::from_millis(1500));
+/// assert_eq!(
+///     duration_from_secs_f64_checked(-1.5).unwrap_err().message(),
+///     "cannot convert -1.5 seconds to Duration: value is negative",
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn duration_from_secs_f64_checked(secs: f64) -> crate::Result<Duration> {
+    duration_from_f64(secs, secs, "seconds")
+}
+
+/// Converts a fractional number of milliseconds to a [`Duration`].
+/// Returns an error if the value is negative, NaN, infinite or too large.
+/// ```
+/// use {cadd::time::duration_from_millis_f64_checked, std::time::Duration};
+///
+/// assert_eq!(duration_from_millis_f64_checked(2.5).unwrap(), Duration::from_micros(2500));
+/// assert!(duration_from_millis_f64_checked(f64::NAN).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn duration_from_millis_f64_checked(millis: f64) -> crate::Result<Duration> {
+    duration_from_f64(millis, millis / 1000.0, "milliseconds")
+}
+
+/// Multiplies a [`Duration`] by the fraction `num / den`. Returns an error on overflow.
+///
+/// The result is computed exactly in integer nanoseconds and truncated to whole nanoseconds,
+/// so, unlike [`Duration::mul_f64`], it doesn't lose precision for long durations.
+/// The intermediate product can't overflow, so an error is only returned if the result
+/// doesn't fit into `Duration`.
+/// ```
+/// use {cadd::time::scale_duration, std::{num::NonZero, time::Duration}};
+///
+/// let three = NonZero::new(3).unwrap();
+/// assert_eq!(scale_duration(Duration::from_secs(1), 2, three).unwrap(), Duration::from_nanos(666_666_666));
+/// assert!(scale_duration(Duration::MAX, 4, three).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn scale_duration(d: Duration, num: u32, den: NonZero<u32>) -> crate::Result<Duration> {
+    // `as_nanos()` is less than 2^94, so multiplying it by a `u32` can't overflow.
+    let nanos = d.as_nanos() * u128::from(num) / u128::from(den.get());
+    match u64::try_from(nanos / 1_000_000_000) {
+        Ok(secs) => Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32)),
+        Err(_) => Err(error!(
+            "scale_duration",
+            "overflow: scale_duration({:?}, {}, {})", d, num, den,
+        )),
+    }
+}
+
+/// Splits a [`Duration`] into `(days, hours, minutes, seconds, nanoseconds)` for display.
+///
+/// Hours are from 0 to 23, minutes and seconds are from 0 to 59, and nanoseconds are
+/// from 0 to 999_999_999. Days are not limited, so this function never fails.
+/// ```
+/// use {cadd::time::duration_components, std::time::Duration};
+///
+/// let d = Duration::new(2 * 86_400 + 3 * 3_600 + 4 * 60 + 5, 6);
+/// assert_eq!(duration_components(d), (2, 3, 4, 5, 6));
+/// ```
+#[inline]
+pub fn duration_components(d: Duration) -> (u64, u8, u8, u8, u32) {
+    // The divisors are non-zero constants and the remainders fit into `u8`.
+    let secs = d.as_secs();
+    let days = secs / 86_400;
+    let hours = (secs % 86_400 / 3_600) as u8;
+    let minutes = (secs % 3_600 / 60) as u8;
+    let seconds = (secs % 60) as u8;
+    (days, hours, minutes, seconds, d.subsec_nanos())
+}
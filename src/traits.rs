@@ -0,0 +1,26 @@
+//! Exports the library's traits without any free functions.
+//!
+//! Use this module if you prefer importing traits by name instead of a wildcard import of the [prelude](crate::prelude):
+//! ```
+//! use cadd::traits::{Cadd, Cmul};
+//!
+//! fn f(a: u32, b: u32) -> cadd::Result<u32> {
+//!     a.cmul(b)?.cadd(1)
+//! }
+//! ```
+//! Free function alternatives to trait methods can be imported from [`funcs`](crate::funcs).
+
+pub use crate::{
+    convert::{
+        CTry, CencodeUtf8, Cfrom, Cinto, IntoType, NonZeroFromOps, ReinterpretFrom,
+        ReinterpretInto, SaturatingFrom, SaturatingInto, SwapBytes, ToBe, ToLe, ToNonZero,
+        ToNonZeroAbs,
+    },
+    ops::{
+        BitField, CILog, CILog10, CILog2, Cabs, Cadd, CaddOption, Cbounded, Cdiv, CdivEuclid,
+        CdivExact, CdivPow2, CdivRound, Cicbrt, Cisqrt, Cmul, Cneg, CnextMultipleOf,
+        CnextPowerOfTwo, Cpow, CpowWidening, Crem, CremEuclid, Cshl, CshlLossless, Cshr,
+        CshrLossless, Csub, CsubOrZero, Distance, SaturatingAbs, WouldOverflow, WrappingReport,
+    },
+    time::{CdivRemDuration, CoffsetNanos, CoffsetSecs, DurationUnits},
+};
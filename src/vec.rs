@@ -0,0 +1,83 @@
+//! Checked operations on vectors.
+//!
+//! Requires the `alloc` feature.
+
+use {crate::error::error, alloc::vec::Vec};
+
+/// Reserves capacity for at least `additional` more elements.
+///
+/// Unlike [`Vec::reserve`], returns an error instead of panicking or aborting if the new capacity
+/// overflows or if the allocation fails. Unlike [`Vec::try_reserve`], the error includes
+/// the requested size.
+/// ```
+/// use cadd::vec::ctry_reserve;
+///
+/// let mut v = vec![1u32, 2];
+/// ctry_reserve(&mut v, 10).unwrap();
+/// assert!(v.capacity() >= 12);
+/// assert!(ctry_reserve(&mut v, usize::MAX).is_err());
+/// ```
+#[inline]
+#[track_caller]
+pub fn ctry_reserve<T>(v: &mut Vec<T>, additional: usize) -> crate::Result<()> {
+    let elem_size = size_of::<T>();
+    let fits = match v.len().checked_add(additional) {
+        Some(len) => len
+            .checked_mul(elem_size)
+            .is_some_and(|size| size <= isize::MAX as usize),
+        None => false,
+    };
+    if !fits {
+        return Err(error!(
+            "reserve",
+            "capacity overflow: {} + {} elements of {} bytes",
+            v.len(),
+            additional,
+            elem_size,
+        ));
+    }
+    if let Err(err) = v.try_reserve(additional) {
+        return Err(error!(
+            "reserve",
+            "cannot reserve {} more elements of {} bytes: {}", additional, elem_size, err,
+        ));
+    }
+    Ok(())
+}
+
+/// Creates an empty vector with space for at least `capacity` elements.
+///
+/// Unlike [`Vec::with_capacity`], returns an error instead of panicking or aborting if the size
+/// of the allocation exceeds `isize::MAX` bytes or if the allocation fails. Use it if `capacity`
+/// comes from untrusted input.
+/// ```
+/// use cadd::vec::cwith_capacity;
+///
+/// assert!(cwith_capacity::<u64>(100).unwrap().capacity() >= 100);
+/// assert_eq!(
+///     cwith_capacity::<u64>(usize::MAX / 4).unwrap_err().message(),
+///     format!("capacity overflow: {} elements of 8 bytes", usize::MAX / 4),
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn cwith_capacity<T>(capacity: usize) -> crate::Result<Vec<T>> {
+    let elem_size = size_of::<T>();
+    let fits = capacity
+        .checked_mul(elem_size)
+        .is_some_and(|size| size <= isize::MAX as usize);
+    if !fits {
+        return Err(error!(
+            "with_capacity",
+            "capacity overflow: {} elements of {} bytes", capacity, elem_size,
+        ));
+    }
+    let mut v = Vec::new();
+    if let Err(err) = v.try_reserve_exact(capacity) {
+        return Err(error!(
+            "with_capacity",
+            "cannot allocate {} elements of {} bytes: {}", capacity, elem_size, err,
+        ));
+    }
+    Ok(v)
+}